@@ -0,0 +1,106 @@
+use linter_test::formatters::fnv1a;
+use linter_test::{DiagnosticWithLocation, LintResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const BASELINE_VERSION: u32 = 1;
+
+/// Known diagnostics that later runs should stay quiet about.
+///
+/// Entries identify a diagnostic by file, rule and a hash of the flagged code (not its line),
+/// so they survive unrelated edits that shift lines around.
+#[derive(Serialize, Deserialize)]
+pub struct Baseline {
+    version: u32,
+    entries: Vec<Entry>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Entry {
+    file: String,
+    rule: String,
+    hash: String,
+}
+
+impl Baseline {
+    pub fn from_results(results: &[LintResult]) -> Self {
+        let mut entries: Vec<Entry> = results.iter()
+            .flat_map(|r| {
+                let file = normalize_path(&r.path);
+                r.diagnostics.iter().map(move |d| Entry::new(&file, &r.source, d))
+            })
+            .collect();
+        entries.sort();
+        Baseline { version: BASELINE_VERSION, entries }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("Failed to read baseline {}: {}", path.display(), e))?;
+        let baseline: Baseline = serde_json::from_str(&content).map_err(|e| format!("Failed to parse baseline {}: {}", path.display(), e))?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(format!("Unsupported baseline version {} in {}", baseline.version, path.display()));
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut content = serde_json::to_string_pretty(self).unwrap();
+        content.push('\n');
+        fs::write(path, content).map_err(|e| format!("Failed to write baseline {}: {}", path.display(), e))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Drops diagnostics recorded in the baseline. Each entry absorbs one diagnostic,
+    /// so a second copy of a known problem is still reported.
+    pub fn filter(&self, results: &mut [LintResult]) {
+        let mut remaining: HashMap<&Entry, usize> = HashMap::new();
+        for entry in &self.entries {
+            *remaining.entry(entry).or_default() += 1;
+        }
+
+        for result in results {
+            let file = normalize_path(&result.path);
+            let known: Vec<bool> = result.diagnostics.iter().map(|d| {
+                match remaining.get_mut(&Entry::new(&file, &result.source, d)) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        true
+                    }
+                    _ => false,
+                }
+            }).collect();
+            let mut known = known.into_iter();
+            result.diagnostics.retain(|_| !known.next().unwrap_or(false));
+        }
+    }
+}
+
+impl Entry {
+    fn new(file: &str, source: &str, d: &DiagnosticWithLocation) -> Self {
+        // Whitespace is dropped so re-indenting or re-wrapping the code doesn't invalidate the entry.
+        let fragment = source.get(d.start_offset..d.end_offset).unwrap_or_default();
+        let fragment: String = fragment.split_whitespace().collect();
+        let hash = fnv1a(&format!("{}\0{}\0{}", d.rule, d.message, fragment));
+        Entry {
+            file: file.to_string(),
+            rule: d.rule.clone(),
+            hash: format!("{:016x}", hash),
+        }
+    }
+}
+
+/// Baselines are committed and shared, so paths are stored relative to the working directory with `/` separators.
+fn normalize_path(path: &str) -> String {
+    let relative = std::env::current_dir().ok()
+        .zip(fs::canonicalize(path).ok())
+        .and_then(|(cwd, abs)| abs.strip_prefix(fs::canonicalize(cwd).ok()?).ok().map(Path::to_path_buf));
+    match relative {
+        Some(relative) => relative.to_string_lossy().replace('\\', "/"),
+        None => path.trim_start_matches("./").replace('\\', "/"),
+    }
+}
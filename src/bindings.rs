@@ -0,0 +1,597 @@
+use crate::css::CssTokenKind;
+use crate::linter::{Context, StyleBlock};
+use crate::template_ast::{Element, TemplateNode};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_ast::AstKind;
+use oxc_ast_visit::{walk, Visit};
+use oxc_parser::Parser;
+use oxc_semantic::{SemanticBuilder, SymbolId};
+use oxc_span::{GetSpan, SourceType, Span};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// How `<script setup>` declarations connect to the template.
+///
+/// All spans are over the original file.
+#[derive(Debug, Default)]
+pub struct Bindings {
+    pub script_setup: bool,
+    /// Top-level value bindings of `<script setup>` (variables, functions, classes, imports).
+    pub setup: Vec<SetupBinding>,
+    /// Props declared with `defineProps`.
+    pub props: Vec<Declared>,
+    /// Events declared with `defineEmits`.
+    pub emits: Vec<Declared>,
+    /// Props read in the script through the `defineProps` result (`props.foo`) or destructured from it.
+    pub props_used_in_script: Vec<String>,
+    /// Free identifiers in template expressions (template-local names already resolved away).
+    pub template_references: Vec<Reference>,
+    /// Free identifiers in style `v-bind()` expressions.
+    pub style_references: Vec<Reference>,
+    /// Element names and custom directives (`v-focus`) used in the template.
+    pub components: Vec<Reference>,
+    /// `v-for` aliases and slot props declared in the template.
+    pub template_locals: Vec<TemplateLocal>,
+    /// Event names passed to `$emit(...)` in the template or to the `defineEmits` result in the script;
+    /// spans cover the name without quotes.
+    pub emitted: Vec<Reference>,
+    /// Message keys passed to `$t(...)`, or a `t(...)` from `useI18n()`, in the template; spans cover the key without quotes.
+    pub translations: Vec<Reference>,
+    /// Local names of the translation functions destructured from `useI18n()` (`const { t } = useI18n()`).
+    pub translation_functions: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SetupBinding {
+    pub name: String,
+    pub symbol_id: SymbolId,
+    pub kind: BindingKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingKind {
+    Variable,
+    Function,
+    Class,
+    Import,
+}
+
+#[derive(Debug)]
+pub struct Declared {
+    pub name: String,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct Reference {
+    pub name: String,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct TemplateLocal {
+    pub name: String,
+    pub kind: TemplateLocalKind,
+    pub span: Span,
+    /// The attribute that declares it (`v-for="..."` or `v-slot="..."`).
+    pub attribute_span: Span,
+    /// Whether any expression in its scope refers to it.
+    pub used: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateLocalKind {
+    VForAlias,
+    SlotProp,
+}
+
+/// Global translation functions of vue-i18n.
+const TRANSLATION_FUNCTIONS: &[&str] = &["$t", "$tc"];
+
+/// Translation functions `useI18n()` returns. A bare `t` is often something else, so these only count
+/// when destructured from `useI18n()` in `<script setup>`.
+const USE_I18N_FUNCTIONS: &[&str] = &["t", "tc"];
+
+/// Globals Vue exposes to template expressions.
+const TEMPLATE_GLOBALS: &[&str] = &[
+    "Infinity", "undefined", "NaN", "isFinite", "isNaN", "parseFloat", "parseInt", "decodeURI",
+    "decodeURIComponent", "encodeURI", "encodeURIComponent", "Math", "Number", "Date", "Array", "Object",
+    "Boolean", "String", "RegExp", "Map", "Set", "JSON", "Intl", "BigInt", "console", "Error", "Symbol",
+];
+
+const BUILTIN_DIRECTIVES: &[&str] = &[
+    "bind", "on", "if", "else", "else-if", "show", "for", "model", "html", "text", "slot", "pre", "cloak", "once", "memo", "is",
+];
+
+impl Bindings {
+    pub fn analyze(ctx: &Context) -> Self {
+        let mut bindings = Bindings { script_setup: ctx.source_file.script_setup, ..Default::default() };
+        if bindings.script_setup {
+            bindings.collect_script(ctx);
+        }
+        if let Some(template) = ctx.template {
+            let mut walker = TemplateWalker { bindings: &mut bindings, offset: ctx.source_file.template_start_offset as u32, scopes: Vec::new() };
+            walker.walk(template);
+        }
+        for style in &ctx.source_file.styles {
+            for (expression, start) in style_v_binds(style) {
+                let Some(info) = analyze_template_code(expression, TemplateCode::Expression) else { continue };
+                bindings.style_references.extend(info.references.into_iter().map(|(name, span)| Reference {
+                    name,
+                    span: style.span(Span::new(start + span.start, start + span.end)),
+                }));
+            }
+        }
+        bindings
+    }
+
+    /// Whether the template refers to `name`, as an identifier, a component tag or a custom directive
+    /// (style `v-bind()` expressions count too).
+    pub fn is_used_in_template(&self, name: &str) -> bool {
+        let normalized = normalize_component_name(name);
+        self.template_references.iter().chain(&self.style_references).any(|r| r.name == name)
+            || self.components.iter().any(|c| normalize_component_name(&c.name) == normalized)
+    }
+
+    /// Whether a template-side reference to `name` resolves: a setup binding, prop or Vue global.
+    /// Always true without `<script setup>`, since Options API members aren't modelled.
+    pub fn is_defined(&self, name: &str) -> bool {
+        !self.script_setup
+            || name.starts_with('$')
+            || TEMPLATE_GLOBALS.contains(&name)
+            || self.setup.iter().any(|b| b.name == name)
+            || self.props.iter().any(|p| p.name == name)
+    }
+
+    /// Template identifiers that nothing defines (template locals are already resolved away).
+    pub fn undefined_template_references(&self) -> impl Iterator<Item = &Reference> {
+        self.template_references.iter().filter(|r| !self.is_defined(&r.name))
+    }
+
+    /// Style `v-bind()` identifiers that nothing defines.
+    pub fn undefined_style_references(&self) -> impl Iterator<Item = &Reference> {
+        self.style_references.iter().filter(|r| !self.is_defined(&r.name))
+    }
+
+    /// Props used neither in the template nor in the script.
+    pub fn unused_props(&self) -> impl Iterator<Item = &Declared> {
+        self.props.iter().filter(|p| {
+            !self.template_references.iter().any(|r| r.name == p.name || r.name == "$props")
+                && !self.props_used_in_script.contains(&p.name)
+        })
+    }
+
+    fn collect_script(&mut self, ctx: &Context) {
+        let scoping = ctx.semantic.scoping();
+        let shift = |span: Span| ctx.script_span(span);
+
+        for (&name, &symbol_id) in scoping.get_bindings(scoping.root_scope_id()) {
+            let flags = scoping.symbol_flags(symbol_id);
+            if !flags.is_value() {
+                continue; // Types can't be used in the template
+            }
+            let kind = if flags.is_import() {
+                BindingKind::Import
+            } else if flags.is_function() {
+                BindingKind::Function
+            } else if flags.is_class() {
+                BindingKind::Class
+            } else {
+                BindingKind::Variable
+            };
+            self.setup.push(SetupBinding { name: name.to_string(), symbol_id, kind, span: shift(scoping.symbol_span(symbol_id)) });
+        }
+        self.setup.sort_by_key(|b| b.span.start);
+
+        // `defineProps` / `defineEmits` are only valid at the top level
+        for stmt in &ctx.program.body {
+            let declarators: Vec<(Option<&BindingPattern>, &Expression)> = match stmt {
+                Statement::VariableDeclaration(decl) => decl.declarations.iter()
+                    .filter_map(|d| d.init.as_ref().map(|init| (Some(&d.id), init)))
+                    .collect(),
+                Statement::ExpressionStatement(expr) => vec![(None, &expr.expression)],
+                _ => continue,
+            };
+
+            for (id, init) in declarators {
+                if let Some(id) = id {
+                    self.translation_functions.extend(use_i18n_functions(id, init));
+                }
+                let Some((macro_name, call)) = compiler_macro(init) else { continue };
+                let declared = match macro_name {
+                    "defineProps" => &mut self.props,
+                    _ => &mut self.emits,
+                };
+                declared.extend(macro_declarations(call, macro_name, ctx.program).into_iter()
+                    .map(|(name, span)| Declared { name, span: shift(span) }));
+
+                let Some(id) = id else { continue };
+                match (&id.kind, macro_name) {
+                    (BindingPatternKind::BindingIdentifier(ident), name) => {
+                        let Some(symbol_id) = ident.symbol_id.get() else { continue };
+                        for &reference_id in scoping.get_resolved_reference_ids(symbol_id) {
+                            let node_id = scoping.get_reference(reference_id).node_id();
+                            match (ctx.semantic.nodes().parent_kind(node_id), name) {
+                                (AstKind::StaticMemberExpression(member), "defineProps") => {
+                                    self.props_used_in_script.push(member.property.name.to_string());
+                                }
+                                (AstKind::CallExpression(call), "defineEmits") => {
+                                    if let Some((event, span)) = first_string_argument(call) {
+                                        self.emitted.push(Reference { name: event, span: shift(Span::new(span.start + 1, span.end - 1)) });
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    (BindingPatternKind::ObjectPattern(pattern), "defineProps") => {
+                        self.props_used_in_script.extend(pattern.properties.iter()
+                            .filter_map(|p| p.key.static_name().map(|n| n.to_string())));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// The local names of `t` / `tc` in `const { t, tc: translate } = useI18n()`.
+fn use_i18n_functions(id: &BindingPattern, init: &Expression) -> Vec<String> {
+    let Expression::CallExpression(call) = init.without_parentheses() else { return Vec::new() };
+    let (Expression::Identifier(callee), BindingPatternKind::ObjectPattern(pattern)) = (&call.callee, &id.kind) else { return Vec::new() };
+    if callee.name != "useI18n" {
+        return Vec::new();
+    }
+    pattern.properties.iter()
+        .filter(|p| p.key.static_name().is_some_and(|key| USE_I18N_FUNCTIONS.contains(&key.as_ref())))
+        .filter_map(|p| match &p.value.kind {
+            BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `defineProps(...)`, `withDefaults(defineProps(...), ...)` or `defineEmits(...)`.
+fn compiler_macro<'e, 'a>(expr: &'e Expression<'a>) -> Option<(&'static str, &'e CallExpression<'a>)> {
+    let Expression::CallExpression(call) = expr.without_parentheses() else { return None };
+    let Expression::Identifier(callee) = &call.callee else { return None };
+    match callee.name.as_str() {
+        "defineProps" => Some(("defineProps", call)),
+        "defineEmits" => Some(("defineEmits", call)),
+        "withDefaults" => call.arguments.first()
+            .and_then(|arg| arg.as_expression())
+            .and_then(compiler_macro)
+            .filter(|(name, _)| *name == "defineProps"),
+        _ => None,
+    }
+}
+
+/// Names declared by a `defineProps` / `defineEmits` call, from its runtime argument or its type argument.
+fn macro_declarations(call: &CallExpression, macro_name: &str, program: &Program) -> Vec<(String, Span)> {
+    let mut names = Vec::new();
+
+    match call.arguments.first().and_then(|arg| arg.as_expression()) {
+        // defineProps(['a', 'b']) / defineEmits(['change'])
+        Some(Expression::ArrayExpression(array)) => {
+            for element in &array.elements {
+                if let ArrayExpressionElement::StringLiteral(lit) = element {
+                    names.push((lit.value.to_string(), lit.span));
+                }
+            }
+        }
+        // defineProps({ a: String }) / defineEmits({ change: (id) => true })
+        Some(Expression::ObjectExpression(object)) => {
+            for property in &object.properties {
+                if let ObjectPropertyKind::ObjectProperty(p) = property
+                    && let Some(name) = p.key.static_name() {
+                    names.push((name.to_string(), p.key.span()));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(ty) = call.type_arguments.as_ref().and_then(|args| args.params.first()) {
+        for signature in type_members(ty, program) {
+            match signature {
+                // { a: string } / { change: [id: number] }
+                TSSignature::TSPropertySignature(p) => {
+                    if let Some(name) = p.key.static_name() {
+                        names.push((name.to_string(), p.key.span()));
+                    }
+                }
+                // { (e: 'change', id: number): void }
+                TSSignature::TSCallSignatureDeclaration(sig) if macro_name == "defineEmits" => {
+                    let event_type = sig.params.items.first()
+                        .and_then(|param| param.pattern.type_annotation.as_ref())
+                        .map(|annotation| &annotation.type_annotation);
+                    let literals: Vec<&TSType> = match event_type {
+                        Some(TSType::TSUnionType(union)) => union.types.iter().collect(),
+                        Some(ty) => vec![ty],
+                        None => Vec::new(),
+                    };
+                    for literal in literals {
+                        if let TSType::TSLiteralType(lit) = literal
+                            && let TSLiteral::StringLiteral(s) = &lit.literal {
+                            names.push((s.value.to_string(), s.span));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    names
+}
+
+/// Members of an object type literal, or of an interface / type alias declared in the same file.
+fn type_members<'p, 'a>(ty: &'p TSType<'a>, program: &'p Program<'a>) -> Vec<&'p TSSignature<'a>> {
+    match ty {
+        TSType::TSTypeLiteral(literal) => literal.members.iter().collect(),
+        TSType::TSTypeReference(reference) => {
+            let TSTypeName::IdentifierReference(name) = &reference.type_name else { return Vec::new() };
+            for stmt in &program.body {
+                let declaration = match stmt {
+                    Statement::ExportNamedDeclaration(export) => export.declaration.as_ref(),
+                    _ => stmt.as_declaration(),
+                };
+                match declaration {
+                    Some(Declaration::TSInterfaceDeclaration(interface)) if interface.id.name == name.name => {
+                        return interface.body.body.iter().collect();
+                    }
+                    Some(Declaration::TSTypeAliasDeclaration(alias)) if alias.id.name == name.name => {
+                        return type_members(&alias.type_annotation, program);
+                    }
+                    _ => {}
+                }
+            }
+            Vec::new()
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn first_string_argument(call: &CallExpression) -> Option<(String, Span)> {
+    match call.arguments.first()? {
+        Argument::StringLiteral(lit) => Some((lit.value.to_string(), lit.span)),
+        _ => None,
+    }
+}
+
+/// The expressions of `v-bind(foo)` / `v-bind('foo.bar')` in a style block, with their start offsets in it.
+fn style_v_binds(style: &StyleBlock) -> Vec<(&str, u32)> {
+    let tokens = style.tokenize();
+    let mut expressions = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        if token.kind != CssTokenKind::Function || token.text(&style.content) != "v-bind(" {
+            continue;
+        }
+        let Some(argument) = tokens[i + 1..].iter().find(|t| t.kind != CssTokenKind::Whitespace) else { continue };
+        let text = argument.text(&style.content);
+        match argument.kind {
+            CssTokenKind::Ident => expressions.push((text, argument.span.start)),
+            CssTokenKind::String if text.len() >= 2 => expressions.push((&text[1..text.len() - 1], argument.span.start + 1)),
+            _ => {}
+        }
+    }
+    expressions
+}
+
+/// Lowercased without dashes or a namespace, so `my-comp`, `MyComp` and `MyComp.Item` compare equal
+/// (and `v-focus` matches a `vFocus` binding).
+fn normalize_component_name(name: &str) -> String {
+    name.split('.').next().unwrap_or(name).replace('-', "").to_ascii_lowercase()
+}
+
+/// The parts of a `v-for` value, with byte offsets into it.
+#[derive(Debug, Clone, Copy)]
+pub struct VForExpression<'s> {
+    pub alias: &'s str,  // `item`, `(item, index)`, `{ id }`
+    pub alias_offset: usize,
+    pub source: &'s str, // `items`, `10`
+    pub source_offset: usize,
+}
+
+/// Splits `alias in source` / `alias of source`. `None` if the value doesn't have that shape.
+pub fn split_v_for(value: &str) -> Option<VForExpression<'_>> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| Regex::new(r"^\s*([\s\S]*?)\s+(?:in|of)\s+([\s\S]*?)\s*$").unwrap());
+    let caps = re.captures(value)?;
+    let (alias, source) = (caps.get(1)?, caps.get(2)?);
+    if alias.as_str().is_empty() || source.as_str().is_empty() {
+        return None;
+    }
+    Some(VForExpression { alias: alias.as_str(), alias_offset: alias.start(), source: source.as_str(), source_offset: source.start() })
+}
+
+/// How a piece of template code is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateCode {
+    /// `:foo="..."`, `v-if="..."`, `{{ ... }}`
+    Expression,
+    /// `@click="..."`, which may also be a statement list
+    Handler,
+    /// `v-for` aliases and slot props: a parameter list
+    Params,
+}
+
+/// What a piece of template code declares and refers to. Spans are relative to the code.
+#[derive(Debug, Default)]
+pub struct TemplateCodeInfo {
+    pub declared: Vec<(String, Span)>,
+    pub references: Vec<(String, Span)>,
+    /// Calls like `$emit('save')`: the callee name, the string argument and the span of its contents.
+    pub string_calls: Vec<(String, String, Span)>,
+}
+
+/// Parses template code on its own. `None` if it isn't valid.
+pub fn analyze_template_code(code: &str, kind: TemplateCode) -> Option<TemplateCodeInfo> {
+    let source = match kind {
+        TemplateCode::Expression => format!("({}\n);", code),
+        TemplateCode::Handler => code.to_string(),
+        TemplateCode::Params if code.trim_start().starts_with('(') => format!(" {}=>{{}}", code),
+        TemplateCode::Params => format!("({}\n)=>{{}}", code),
+    };
+    let offset = if kind == TemplateCode::Handler { 0 } else { 1 };
+    let shift = |span: Span| Span::new(span.start.saturating_sub(offset), span.end.saturating_sub(offset));
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source, SourceType::ts()).parse();
+    if !ret.errors.is_empty() {
+        return None;
+    }
+    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+    let scoping = semantic.scoping();
+
+    let mut info = TemplateCodeInfo::default();
+    if kind == TemplateCode::Params {
+        info.declared = scoping.symbol_ids()
+            .map(|id| (scoping.symbol_name(id).to_string(), shift(scoping.symbol_span(id))))
+            .collect();
+    }
+    for (name, reference_ids) in scoping.root_unresolved_references() {
+        for &reference_id in reference_ids {
+            let span = semantic.reference_span(scoping.get_reference(reference_id));
+            info.references.push((name.to_string(), shift(span)));
+        }
+    }
+    info.references.sort_by_key(|(_, span)| span.start);
+
+    let mut calls = StringCallCollector::default();
+    calls.visit_program(&ret.program);
+    info.string_calls = calls.0.into_iter().map(|(callee, argument, span)| (callee, argument, shift(span))).collect();
+
+    Some(info)
+}
+
+/// Calls of plain functions whose first argument is a string literal (`$emit('save')`, `$t('title')`).
+#[derive(Default)]
+struct StringCallCollector(Vec<(String, String, Span)>);
+
+impl<'a> Visit<'a> for StringCallCollector {
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::Identifier(ident) = &call.callee
+            && let Some((argument, span)) = first_string_argument(call) {
+            // Just the contents, without the quotes
+            self.0.push((ident.name.to_string(), argument, Span::new(span.start + 1, span.end - 1)));
+        }
+        walk::walk_call_expression(self, call);
+    }
+}
+
+struct TemplateWalker<'b> {
+    bindings: &'b mut Bindings,
+    offset: u32, // template_start_offset
+    scopes: Vec<Vec<usize>>, // Indices into `bindings.template_locals`, innermost last
+}
+
+impl TemplateWalker<'_> {
+    fn walk(&mut self, nodes: &[TemplateNode]) {
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let mustache = RE.get_or_init(|| Regex::new(r"(?s)\{\{(.*?)\}\}").unwrap());
+        for node in nodes {
+            match node {
+                TemplateNode::Element(element) => self.element(element),
+                TemplateNode::Text(text) => {
+                    for cap in mustache.captures_iter(&text.content) {
+                        let inner = cap.get(1).unwrap();
+                        self.code(inner.as_str(), text.span.start + inner.start() as u32, TemplateCode::Expression);
+                    }
+                }
+                TemplateNode::Comment(_) => {}
+            }
+        }
+    }
+
+    fn element(&mut self, element: &Element) {
+        self.bindings.components.push(Reference { name: element.name.clone(), span: self.abs(element.span) });
+        let scope_depth = self.scopes.len();
+
+        // The `v-for` source is evaluated outside the loop; its aliases are visible to the element itself
+        if let Some(attr) = element.directive("for")
+            && let (Some(value), Some(value_span)) = (&attr.value, attr.value_span)
+            && let Some(v_for) = split_v_for(value) {
+            self.code(v_for.source, value_span.start + v_for.source_offset as u32, TemplateCode::Expression);
+            self.declare(v_for.alias, value_span.start + v_for.alias_offset as u32, TemplateLocalKind::VForAlias, attr.span);
+        }
+
+        for attr in &element.attributes {
+            let Some(directive) = attr.directive() else { continue };
+            if !BUILTIN_DIRECTIVES.contains(&directive.name) {
+                self.bindings.components.push(Reference { name: format!("v-{}", directive.name), span: self.abs(attr.span) });
+            }
+            let kind = match directive.name {
+                "for" | "slot" => continue,
+                "on" => TemplateCode::Handler,
+                _ => TemplateCode::Expression,
+            };
+            if let (Some(value), Some(value_span)) = (&attr.value, attr.value_span) {
+                self.code(value, value_span.start, kind);
+            }
+        }
+
+        // Slot props are only visible to the children
+        if let Some(attr) = element.directive("slot")
+            && let (Some(value), Some(value_span)) = (&attr.value, attr.value_span) {
+            self.declare(value, value_span.start, TemplateLocalKind::SlotProp, attr.span);
+        }
+
+        self.walk(&element.children);
+        self.scopes.truncate(scope_depth);
+    }
+
+    /// Records the references in `code` (at `start`, relative to the template), resolving template locals first.
+    fn code(&mut self, code: &str, start: u32, kind: TemplateCode) {
+        if code.trim().is_empty() {
+            return;
+        }
+        let Some(info) = analyze_template_code(code, kind) else { return };
+        self.resolve(info.references, start);
+        for (callee, argument, span) in info.string_calls {
+            let reference = Reference { name: argument, span: self.abs(Span::new(start + span.start, start + span.end)) };
+            if callee == "$emit" {
+                self.bindings.emitted.push(reference);
+            } else if TRANSLATION_FUNCTIONS.contains(&callee.as_str()) || self.bindings.translation_functions.contains(&callee) {
+                self.bindings.translations.push(reference);
+            }
+        }
+    }
+
+    fn declare(&mut self, params: &str, start: u32, kind: TemplateLocalKind, attribute_span: Span) {
+        let Some(info) = analyze_template_code(params, TemplateCode::Params) else { return };
+        self.resolve(info.references, start); // Default values
+        let mut scope = Vec::new();
+        for (name, span) in info.declared {
+            scope.push(self.bindings.template_locals.len());
+            self.bindings.template_locals.push(TemplateLocal {
+                name,
+                kind,
+                span: self.abs(Span::new(start + span.start, start + span.end)),
+                attribute_span: self.abs(attribute_span),
+                used: false,
+            });
+        }
+        self.scopes.push(scope);
+    }
+
+    fn resolve(&mut self, references: Vec<(String, Span)>, start: u32) {
+        for (name, span) in references {
+            let local = self.scopes.iter().rev().flatten().copied()
+                .find(|&i| self.bindings.template_locals[i].name == name);
+            match local {
+                Some(i) => self.bindings.template_locals[i].used = true,
+                None => {
+                    let span = self.abs(Span::new(start + span.start, start + span.end));
+                    self.bindings.template_references.push(Reference { name, span });
+                }
+            }
+        }
+    }
+
+    fn abs(&self, span: Span) -> Span {
+        Span::new(span.start + self.offset, span.end + self.offset)
+    }
+}
@@ -0,0 +1,163 @@
+use linter_test::line_index::LineIndex;
+use linter_test::{DiagnosticWithLocation, Fix, LintResult, LinterConfig, Severity, Suggestion};
+use oxc_span::Span;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// On-disk lint results, so unchanged files can be skipped on the next run.
+///
+/// The whole cache is discarded when the linter version or the resolved config changes;
+/// a single entry is discarded when its file's content hash no longer matches.
+#[derive(Serialize, Deserialize)]
+pub struct Cache {
+    version: String,
+    config_hash: u64,
+    files: BTreeMap<String, Entry>,
+    #[serde(skip)]
+    location: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    content_hash: u64,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+/// Line/column positions are recomputed from the offsets on replay.
+#[derive(Serialize, Deserialize)]
+struct CachedDiagnostic {
+    rule: String,
+    severity: Severity,
+    message: String,
+    start_offset: usize,
+    end_offset: usize,
+    help: Option<String>,
+    fix: Option<CachedEdit>,
+    suggestions: Vec<CachedSuggestion>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedEdit {
+    start: u32,
+    end: u32,
+    text: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedSuggestion {
+    message: String,
+    edits: Vec<CachedEdit>,
+}
+
+impl Cache {
+    /// Loads the cache at `location`, or starts an empty one if it is missing, unreadable or stale.
+    pub fn load(location: &Path, config: &LinterConfig) -> Self {
+        let version = env!("CARGO_PKG_VERSION").to_string();
+        let config_hash = hash(&serde_json::to_string(&serde_json::to_value(config).unwrap()).unwrap());
+
+        let cached = fs::read_to_string(location).ok()
+            .and_then(|content| serde_json::from_str::<Cache>(&content).ok())
+            .filter(|cache| cache.version == version && cache.config_hash == config_hash);
+
+        let mut cache = cached.unwrap_or(Cache { version, config_hash, files: BTreeMap::new(), location: PathBuf::new() });
+        cache.location = location.to_path_buf();
+        cache
+    }
+
+    /// Replays the cached result for `path` if its content hasn't changed since it was stored.
+    /// With `fix`, entries that still have fixes to apply are treated as misses.
+    pub fn get(&self, path: &Path, content: &str, fix: bool) -> Option<LintResult> {
+        let entry = self.files.get(&path.to_string_lossy().to_string())?;
+        if entry.content_hash != hash(content) || (fix && entry.diagnostics.iter().any(|d| d.fix.is_some())) {
+            return None;
+        }
+
+        let line_index = LineIndex::new(content);
+        let diagnostics = entry.diagnostics.iter().map(|d| {
+            let start = line_index.line_col(d.start_offset);
+            let end = line_index.line_col(d.end_offset);
+            DiagnosticWithLocation {
+                rule: d.rule.clone(),
+                severity: d.severity,
+                message: d.message.clone(),
+                start_line: start.line,
+                start_column: start.column,
+                end_line: end.line,
+                end_column: end.column,
+                start_column_utf8: start.column_utf8,
+                end_column_utf8: end.column_utf8,
+                start_offset: d.start_offset,
+                end_offset: d.end_offset,
+                fix_available: d.fix.is_some(),
+                help: d.help.clone(),
+                fix: d.fix.as_ref().map(CachedEdit::to_fix),
+                suggestions: d.suggestions.iter().map(|s| Suggestion {
+                    message: s.message.clone(),
+                    edits: s.edits.iter().map(CachedEdit::to_fix).collect(),
+                }).collect(),
+            }
+        }).collect();
+
+        Some(LintResult {
+            path: path.to_string_lossy().to_string(),
+            diagnostics,
+            fixed_count: 0,
+            source: content.to_string(),
+            fixed_source: None,
+            fix_error: None,
+        })
+    }
+
+    /// Stores a fresh result. Results whose fixes were written back describe the old content, so they are dropped.
+    pub fn insert(&mut self, result: &LintResult) {
+        if result.fixed_source.is_some() {
+            self.files.remove(&result.path);
+            return;
+        }
+
+        let diagnostics = result.diagnostics.iter().map(|d| CachedDiagnostic {
+            rule: d.rule.clone(),
+            severity: d.severity,
+            message: d.message.clone(),
+            start_offset: d.start_offset,
+            end_offset: d.end_offset,
+            help: d.help.clone(),
+            fix: d.fix.as_ref().map(CachedEdit::from_fix),
+            suggestions: d.suggestions.iter().map(|s| CachedSuggestion {
+                message: s.message.clone(),
+                edits: s.edits.iter().map(CachedEdit::from_fix).collect(),
+            }).collect(),
+        }).collect();
+
+        self.files.insert(result.path.clone(), Entry { content_hash: hash(&result.source), diagnostics });
+    }
+
+    /// Prunes entries for deleted files and writes the cache back to disk.
+    pub fn save(&mut self) {
+        self.files.retain(|path, _| Path::new(path).is_file());
+        let content = serde_json::to_string(&*self).unwrap();
+        if let Err(e) = fs::write(&self.location, content) {
+            eprintln!("Failed to write cache to {}: {}", self.location.display(), e);
+        }
+    }
+}
+
+impl CachedEdit {
+    fn from_fix(fix: &Fix) -> Self {
+        Self { start: fix.span.start, end: fix.span.end, text: fix.replacement.clone() }
+    }
+
+    fn to_fix(&self) -> Fix {
+        Fix { span: Span::new(self.start, self.end), replacement: self.text.clone() }
+    }
+}
+
+/// `DefaultHasher` may change between Rust releases; that only turns hits into misses.
+fn hash(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
@@ -1,17 +1,101 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use linter_test::formatters::OutputFormat;
+use linter_test::RuleCategory;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Files to parse
-    #[arg(required = true)]
+    #[arg(required_unless_present = "stdin")]
     pub files: Vec<String>,
 
+    /// Lint content read from stdin instead of files (with `--fix`, the fixed content is written to stdout)
+    #[arg(long, conflicts_with = "files")]
+    pub stdin: bool,
+
+    /// Path used for stdin content (picks rules/parser settings and appears in the report)
+    #[arg(long, requires = "stdin", default_value = "stdin.vue")]
+    pub stdin_filename: String,
+
+    /// File extensions to lint, overriding `extensions` in .linterrc.json (e.g. `--ext vue,ts`)
+    #[arg(long, value_name = "EXT", value_delimiter = ',')]
+    pub ext: Vec<String>,
+
+    /// Skip paths matching this pattern (.gitignore syntax; repeatable)
+    #[arg(long, value_name = "PATTERN")]
+    pub ignore_pattern: Vec<String>,
+
+    /// Don't apply `.linterignore`, `ignorePatterns` or `--ignore-pattern`
+    #[arg(long)]
+    pub no_ignore: bool,
+
+    /// Only lint files changed since this git ref (plus untracked files)
+    #[arg(long, value_name = "REF", conflicts_with = "staged")]
+    pub changed_since: Option<String>,
+
+    /// Only lint files with staged changes
+    #[arg(long)]
+    pub staged: bool,
+
+    /// Only report diagnostics on changed lines (needs `--changed-since` or `--staged`)
+    #[arg(long, conflicts_with = "fix")]
+    pub diff_lines_only: bool,
+
+    /// Skip files that haven't changed since the last run, replaying their cached results
+    #[arg(long, overrides_with = "no_cache")]
+    pub cache: bool,
+
+    /// Where to store the cache
+    #[arg(long, value_name = "PATH", default_value = ".linter-cache")]
+    pub cache_location: String,
+
+    /// Disable the cache (overrides an earlier `--cache`)
+    #[arg(long, overrides_with = "cache")]
+    pub no_cache: bool,
+
+    /// Record all current diagnostics in a baseline file
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = ".linter-baseline.json", conflicts_with = "baseline")]
+    pub write_baseline: Option<String>,
+
+    /// Only report diagnostics that aren't in the baseline file
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = ".linter-baseline.json")]
+    pub baseline: Option<String>,
+
+    /// Keep running and re-lint files as they change
+    #[arg(long, conflicts_with = "stdin")]
+    pub watch: bool,
+
     /// Apply auto-fixes
     #[arg(long)]
     pub fix: bool,
 
-    /// Output results in JSON format
+    /// Only apply fixes of these categories (comma separated)
+    #[arg(long, value_enum, value_delimiter = ',', requires = "fix")]
+    pub fix_type: Vec<RuleCategory>,
+
+    /// Only apply fixes from these rules (comma separated, e.g. `vue/mustache-interpolation-spacing`)
+    #[arg(long, value_delimiter = ',', requires = "fix")]
+    pub fix_rule: Vec<String>,
+
+    /// Output results in JSON format (same as `--format json`)
     #[arg(long)]
     pub json: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+
+    /// Write the report to this file instead of stdout (the summary still goes to stdout)
+    #[arg(long)]
+    pub output_file: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run as a Language Server (LSP over stdio)
+    Lsp,
 }
@@ -0,0 +1,179 @@
+use oxc_span::Span;
+
+/// A CSS token. Spans are relative to the style block content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CssToken {
+    pub kind: CssTokenKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssTokenKind {
+    Whitespace,
+    Comment,
+    Ident,       // `color`, `--main-color`, `-webkit-box`
+    Function,    // `v-bind(`, `rgba(`: the name plus the opening paren
+    Url,         // `url(...)` with an unquoted argument, as one token
+    AtKeyword,   // `@media`
+    Hash,        // `#fff`, `#id`
+    String,      // Quotes included
+    Number,      // Units included: `10px`, `50%`, `.5em`
+    Interpolation, // SCSS / Less `#{...}`
+    Colon,
+    Semicolon,
+    Comma,
+    OpenBrace,
+    CloseBrace,
+    OpenParen,
+    CloseParen,
+    OpenBracket,
+    CloseBracket,
+    Delim(char), // Anything else: `.`, `>`, `!`, `&`, `$`, ...
+}
+
+impl CssToken {
+    pub fn text<'s>(&self, source: &'s str) -> &'s str {
+        &source[self.span.start as usize..self.span.end as usize]
+    }
+}
+
+/// Tokenizes a style block. Lenient: never fails, unterminated strings and comments run to the end.
+/// `line_comments` enables `//` comments (SCSS, Less, Stylus).
+pub fn tokenize(source: &str, line_comments: bool) -> Vec<CssToken> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        let b = bytes[i];
+        let kind = match b {
+            b' ' | b'\t' | b'\n' | b'\r' | b'\x0C' => {
+                while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+                CssTokenKind::Whitespace
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = source[i + 2..].find("*/").map_or(bytes.len(), |end| i + 2 + end + 2);
+                CssTokenKind::Comment
+            }
+            b'/' if line_comments && bytes.get(i + 1) == Some(&b'/') => {
+                i = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+                CssTokenKind::Comment
+            }
+            b'"' | b'\'' => {
+                i = skip_string(bytes, i);
+                CssTokenKind::String
+            }
+            b'#' if bytes.get(i + 1) == Some(&b'{') => {
+                i = source[i..].find('}').map_or(bytes.len(), |end| i + end + 1);
+                CssTokenKind::Interpolation
+            }
+            b'#' if bytes.get(i + 1).is_some_and(|&c| is_name_char(c)) => {
+                i = skip_name(bytes, i + 1);
+                CssTokenKind::Hash
+            }
+            b'@' if starts_ident(bytes, i + 1) => {
+                i = skip_name(bytes, i + 1);
+                CssTokenKind::AtKeyword
+            }
+            _ if starts_number(bytes, i) => {
+                i += 1;
+                while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+                    i += 1;
+                }
+                if i < bytes.len() && bytes[i] == b'%' {
+                    i += 1;
+                } else if starts_ident(bytes, i) {
+                    i = skip_name(bytes, i);
+                }
+                CssTokenKind::Number
+            }
+            _ if starts_ident(bytes, i) => {
+                i = skip_name(bytes, i);
+                if bytes.get(i) == Some(&b'(') {
+                    i += 1;
+                    if source[start..i].eq_ignore_ascii_case("url(") && !url_is_quoted(bytes, i) {
+                        i = source[i..].find(')').map_or(bytes.len(), |end| i + end + 1);
+                        CssTokenKind::Url
+                    } else {
+                        CssTokenKind::Function
+                    }
+                } else {
+                    CssTokenKind::Ident
+                }
+            }
+            _ => {
+                let c = source[i..].chars().next().unwrap();
+                i += c.len_utf8();
+                match c {
+                    ':' => CssTokenKind::Colon,
+                    ';' => CssTokenKind::Semicolon,
+                    ',' => CssTokenKind::Comma,
+                    '{' => CssTokenKind::OpenBrace,
+                    '}' => CssTokenKind::CloseBrace,
+                    '(' => CssTokenKind::OpenParen,
+                    ')' => CssTokenKind::CloseParen,
+                    '[' => CssTokenKind::OpenBracket,
+                    ']' => CssTokenKind::CloseBracket,
+                    c => CssTokenKind::Delim(c),
+                }
+            }
+        };
+        tokens.push(CssToken { kind, span: Span::new(start as u32, i as u32) });
+    }
+
+    tokens
+}
+
+fn is_name_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'-' || b == b'\\' || b >= 0x80
+}
+
+fn starts_ident(bytes: &[u8], i: usize) -> bool {
+    match bytes.get(i) {
+        Some(b'-') => bytes.get(i + 1).is_some_and(|&c| c == b'-' || (is_name_char(c) && !c.is_ascii_digit())),
+        Some(&c) => c.is_ascii_alphabetic() || c == b'_' || c == b'\\' || c >= 0x80,
+        None => false,
+    }
+}
+
+fn starts_number(bytes: &[u8], i: usize) -> bool {
+    let digit_at = |j: usize| bytes.get(j).is_some_and(u8::is_ascii_digit);
+    match bytes[i] {
+        b'0'..=b'9' => true,
+        b'.' => digit_at(i + 1),
+        b'+' | b'-' => digit_at(i + 1) || (bytes.get(i + 1) == Some(&b'.') && digit_at(i + 2)),
+        _ => false,
+    }
+}
+
+fn skip_name(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && is_name_char(bytes[i]) {
+        // An escape swallows the next character, whatever it is
+        i += if bytes[i] == b'\\' { 2 } else { 1 };
+    }
+    i.min(bytes.len())
+}
+
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b if b == quote => return i + 1,
+            b'\n' => return i, // Unterminated; CSS strings can't span lines
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn url_is_quoted(bytes: &[u8], mut i: usize) -> bool {
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    matches!(bytes.get(i), Some(b'"' | b'\''))
+}
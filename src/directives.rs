@@ -0,0 +1,47 @@
+use crate::line_index::LineIndex;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Inline disable comments:
+/// `// linter-disable-next-line rule-a, rule-b`, `/* linter-disable-line */`,
+/// `<!-- linter-disable-next-line rule-a -->`. Without a rule list every rule is disabled.
+pub struct DisableDirectives {
+    entries: Vec<(usize, Option<Vec<String>>)>, // (1-based line, rules; None = all)
+}
+
+impl DisableDirectives {
+    pub fn parse(content: &str, line_index: &LineIndex) -> Self {
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let re = RE.get_or_init(|| {
+            Regex::new(r"(?m)(?://|/\*|<!--)\s*linter-disable-(next-line|line)\b([^\n]*?)\s*(?:\*/|-->|$)").unwrap()
+        });
+
+        let entries = re.captures_iter(content).filter_map(|cap| {
+            let full = cap.get(0)?;
+            let comment_line = line_index.line_col(full.start()).line;
+            let line = if &cap[1] == "next-line" { comment_line + 1 } else { comment_line };
+            let rules: Vec<String> = cap[2].split(',')
+                .map(|r| r.trim().to_string())
+                .filter(|r| !r.is_empty())
+                .collect();
+            Some((line, if rules.is_empty() { None } else { Some(rules) }))
+        }).collect();
+
+        Self { entries }
+    }
+
+    pub fn is_disabled(&self, rule_name: &str, line: usize) -> bool {
+        self.entries.iter().any(|(l, rules)| {
+            *l == line && rules.as_ref().is_none_or(|rules| rules.iter().any(|r| r == rule_name))
+        })
+    }
+}
+
+/// The comment that disables `rule_name` on the following line.
+pub fn disable_next_line_comment(rule_name: &str, in_script: bool) -> String {
+    if in_script {
+        format!("// linter-disable-next-line {}", rule_name)
+    } else {
+        format!("<!-- linter-disable-next-line {} -->", rule_name)
+    }
+}
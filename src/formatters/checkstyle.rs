@@ -0,0 +1,31 @@
+use crate::formatters::{xml_escape, Formatter};
+use crate::linter::{LintResult, Severity};
+
+/// Checkstyle XML, understood by most CI report plugins.
+pub struct Checkstyle;
+
+impl Formatter for Checkstyle {
+    fn format(&self, results: &[LintResult]) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<checkstyle version=\"4.3\">\n");
+        for result in results {
+            out.push_str(&format!("  <file name=\"{}\">\n", xml_escape(&result.path)));
+            for d in &result.diagnostics {
+                let severity = match d.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                out.push_str(&format!(
+                    "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\" />\n",
+                    d.start_line,
+                    d.start_column,
+                    severity,
+                    xml_escape(&d.message),
+                    xml_escape(&d.rule),
+                ));
+            }
+            out.push_str("  </file>\n");
+        }
+        out.push_str("</checkstyle>\n");
+        out
+    }
+}
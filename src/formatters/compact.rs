@@ -0,0 +1,25 @@
+use crate::formatters::Formatter;
+use crate::linter::LintResult;
+
+/// One line per diagnostic.
+pub struct Compact;
+
+impl Formatter for Compact {
+    fn format(&self, results: &[LintResult]) -> String {
+        let mut out = String::new();
+        for result in results {
+            let mut diagnostics: Vec<_> = result.diagnostics.iter().collect();
+            diagnostics.sort_by_key(|d| d.start_offset);
+            for d in diagnostics {
+                out.push_str(&format!("❌ {} ({}:{}) - {} [{}]\n",
+                    result.path,
+                    d.start_line,
+                    d.start_column,
+                    d.message,
+                    if d.fix_available { "🔧" } else { "" }
+                ));
+            }
+        }
+        out
+    }
+}
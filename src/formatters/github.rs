@@ -0,0 +1,40 @@
+use crate::formatters::Formatter;
+use crate::linter::{LintResult, Severity};
+
+/// GitHub Actions workflow commands (`::error file=...::message`).
+/// These show up as inline annotations on the PR diff.
+pub struct Github;
+
+impl Formatter for Github {
+    fn format(&self, results: &[LintResult]) -> String {
+        let mut out = String::new();
+        for result in results {
+            for d in &result.diagnostics {
+                let command = match d.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                out.push_str(&format!(
+                    "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}\n",
+                    command,
+                    escape_property(&result.path),
+                    d.start_line,
+                    d.start_column,
+                    d.end_line,
+                    d.end_column,
+                    escape_property(&d.rule),
+                    escape_data(&d.message),
+                ));
+            }
+        }
+        out
+    }
+}
+
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
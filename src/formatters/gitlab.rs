@@ -0,0 +1,36 @@
+use crate::formatters::{fnv1a, Formatter};
+use crate::linter::{LintResult, Severity};
+use serde_json::json;
+
+/// GitLab Code Quality report (a JSON array of issues).
+pub struct Gitlab;
+
+impl Formatter for Gitlab {
+    fn format(&self, results: &[LintResult]) -> String {
+        let mut issues = Vec::new();
+        for result in results {
+            for d in &result.diagnostics {
+                let severity = match d.severity {
+                    Severity::Error => "major",
+                    Severity::Warning => "minor",
+                };
+                // GitLab dedupes issues across pipelines by fingerprint, so it must be stable.
+                let fingerprint = fnv1a(&format!("{}\0{}\0{}\0{}", result.path, d.rule, d.message, d.start_offset));
+                issues.push(json!({
+                    "type": "issue",
+                    "check_name": d.rule,
+                    "description": d.message,
+                    "severity": severity,
+                    "fingerprint": format!("{:016x}", fingerprint),
+                    "location": {
+                        "path": result.path,
+                        "lines": { "begin": d.start_line, "end": d.end_line },
+                    },
+                }));
+            }
+        }
+        let mut out = serde_json::to_string_pretty(&issues).unwrap();
+        out.push('\n');
+        out
+    }
+}
@@ -0,0 +1,131 @@
+use crate::formatters::Formatter;
+use crate::linter::{DiagnosticWithLocation, Fix, LintResult, Severity};
+use serde::Serialize;
+
+/// Version of the JSON report shape described by `schemas/report.v1.schema.json`.
+/// Bump it (and add a new schema file) for any breaking change.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Report<'a> {
+    version: u32,
+    results: Vec<FileResult<'a>>,
+    summary: Summary,
+}
+
+#[derive(Serialize)]
+struct FileResult<'a> {
+    path: &'a str,
+    diagnostics: Vec<JsonDiagnostic<'a>>,
+    error_count: usize,
+    warning_count: usize,
+    fixable_count: usize,
+    fixed_count: usize,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    rule_id: &'a str,
+    severity: Severity,
+    message: &'a str,
+    range: Range,
+    fix: Option<Edit<'a>>,
+    suggestions: Vec<JsonSuggestion<'a>>,
+}
+
+#[derive(Serialize)]
+struct Range {
+    start: Position,
+    end: Position,
+}
+
+#[derive(Serialize)]
+struct Position {
+    offset: usize,      // UTF-8 byte offset into the file
+    line: usize,        // 1-based
+    column: usize,      // 1-based, UTF-16 code units
+    column_utf8: usize, // 1-based, UTF-8 bytes
+}
+
+#[derive(Serialize)]
+struct Edit<'a> {
+    range: [u32; 2], // UTF-8 byte offsets, end exclusive
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonSuggestion<'a> {
+    message: &'a str,
+    edits: Vec<Edit<'a>>,
+}
+
+#[derive(Serialize, Default)]
+struct Summary {
+    file_count: usize,
+    error_count: usize,
+    warning_count: usize,
+    fixable_count: usize,
+    fixed_count: usize,
+}
+
+/// Versioned JSON report with per-file results and a summary block.
+pub struct Json;
+
+impl Formatter for Json {
+    fn format(&self, results: &[LintResult]) -> String {
+        let mut summary = Summary { file_count: results.len(), ..Summary::default() };
+
+        let results: Vec<FileResult> = results.iter().map(|result| {
+            let count = |severity| result.diagnostics.iter().filter(|d| d.severity == severity).count();
+            let file_result = FileResult {
+                path: &result.path,
+                diagnostics: result.diagnostics.iter().map(to_json).collect(),
+                error_count: count(Severity::Error),
+                warning_count: count(Severity::Warning),
+                fixable_count: result.diagnostics.iter().filter(|d| d.fix_available).count(),
+                fixed_count: result.fixed_count,
+            };
+            summary.error_count += file_result.error_count;
+            summary.warning_count += file_result.warning_count;
+            summary.fixable_count += file_result.fixable_count;
+            summary.fixed_count += file_result.fixed_count;
+            file_result
+        }).collect();
+
+        let report = Report { version: SCHEMA_VERSION, results, summary };
+        let mut out = serde_json::to_string_pretty(&report).unwrap();
+        out.push('\n');
+        out
+    }
+}
+
+fn to_json(d: &DiagnosticWithLocation) -> JsonDiagnostic<'_> {
+    JsonDiagnostic {
+        rule_id: &d.rule,
+        severity: d.severity,
+        message: &d.message,
+        range: Range {
+            start: Position {
+                offset: d.start_offset,
+                line: d.start_line,
+                column: d.start_column,
+                column_utf8: d.start_column_utf8,
+            },
+            end: Position {
+                offset: d.end_offset,
+                line: d.end_line,
+                column: d.end_column,
+                column_utf8: d.end_column_utf8,
+            },
+        },
+        fix: d.fix.as_ref().map(to_edit),
+        suggestions: d.suggestions.iter().map(|s| JsonSuggestion {
+            message: &s.message,
+            edits: s.edits.iter().map(to_edit).collect(),
+        }).collect(),
+    }
+}
+
+fn to_edit(fix: &Fix) -> Edit<'_> {
+    Edit { range: [fix.span.start, fix.span.end], text: &fix.replacement }
+}
@@ -0,0 +1,47 @@
+use crate::formatters::{xml_escape, Formatter};
+use crate::linter::LintResult;
+
+/// JUnit XML: one test suite per file, one failing test case per diagnostic.
+/// Files without diagnostics get a single passing test case so they show up in the report.
+pub struct Junit;
+
+impl Formatter for Junit {
+    fn format(&self, results: &[LintResult]) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<testsuites>\n");
+        for result in results {
+            let path = xml_escape(&result.path);
+            let failures = result.diagnostics.len();
+            out.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+                path,
+                failures.max(1),
+                failures,
+            ));
+            if result.diagnostics.is_empty() {
+                out.push_str(&format!("    <testcase name=\"{}\" classname=\"{}\" />\n", path, path));
+            }
+            for d in &result.diagnostics {
+                out.push_str(&format!(
+                    "    <testcase name=\"{}:{} {}\" classname=\"{}\">\n",
+                    d.start_line,
+                    d.start_column,
+                    xml_escape(&d.rule),
+                    path,
+                ));
+                out.push_str(&format!(
+                    "      <failure message=\"{}\" type=\"{}\">{} ({}:{}:{})</failure>\n",
+                    xml_escape(&d.message),
+                    xml_escape(&d.rule),
+                    xml_escape(&d.message),
+                    path,
+                    d.start_line,
+                    d.start_column,
+                ));
+                out.push_str("    </testcase>\n");
+            }
+            out.push_str("  </testsuite>\n");
+        }
+        out.push_str("</testsuites>\n");
+        out
+    }
+}
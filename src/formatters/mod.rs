@@ -0,0 +1,89 @@
+pub mod checkstyle;
+pub mod compact;
+pub mod github;
+pub mod gitlab;
+pub mod json;
+pub mod junit;
+pub mod pretty;
+pub mod sarif;
+pub mod stylish;
+
+use crate::linter::LintResult;
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Code frame with the span underlined (default)
+    Pretty,
+    /// Grouped by file, one row per diagnostic (ESLint style)
+    Stylish,
+    /// One line per diagnostic
+    Compact,
+    /// Versioned JSON report (see `schemas/report.v1.schema.json`)
+    Json,
+    /// SARIF 2.1.0 (GitHub code scanning etc.)
+    Sarif,
+    /// JUnit XML
+    Junit,
+    /// Checkstyle XML
+    Checkstyle,
+    /// GitHub Actions workflow annotations
+    Github,
+    /// GitLab Code Quality JSON
+    Gitlab,
+}
+
+impl OutputFormat {
+    /// Human-readable formats get the banner and summary lines around them.
+    pub fn is_human(&self) -> bool {
+        matches!(self, OutputFormat::Pretty | OutputFormat::Stylish | OutputFormat::Compact)
+    }
+}
+
+pub trait Formatter {
+    fn format(&self, results: &[LintResult]) -> String;
+}
+
+pub fn get_formatter(format: OutputFormat, color: bool) -> Box<dyn Formatter> {
+    match format {
+        OutputFormat::Pretty => Box::new(pretty::Pretty { color }),
+        OutputFormat::Stylish => Box::new(stylish::Stylish),
+        OutputFormat::Compact => Box::new(compact::Compact),
+        OutputFormat::Json => Box::new(json::Json),
+        OutputFormat::Sarif => Box::new(sarif::Sarif),
+        OutputFormat::Junit => Box::new(junit::Junit),
+        OutputFormat::Checkstyle => Box::new(checkstyle::Checkstyle),
+        OutputFormat::Github => Box::new(github::Github),
+        OutputFormat::Gitlab => Box::new(gitlab::Gitlab),
+    }
+}
+
+/// Escapes text for XML attribute values and text nodes.
+/// Line breaks and tabs become character references, since parsers normalize them to spaces in attributes.
+pub(crate) fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            '\r' => out.push_str("&#13;"),
+            '\t' => out.push_str("&#9;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher`, the output never changes between Rust releases.
+pub fn fnv1a(s: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for b in s.bytes() {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
@@ -0,0 +1,89 @@
+use crate::formatters::Formatter;
+use crate::linter::{DiagnosticWithLocation, LintResult, Severity};
+use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource, SourceCode};
+use std::fmt;
+use std::sync::Arc;
+
+/// One diagnostic wrapped so miette can render it as a code frame.
+struct Report<'a> {
+    diagnostic: &'a DiagnosticWithLocation,
+    source: &'a NamedSource<Arc<str>>,
+}
+
+impl fmt::Debug for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Report").field("diagnostic", self.diagnostic).finish()
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.diagnostic.message)
+    }
+}
+
+impl std::error::Error for Report<'_> {}
+
+impl miette::Diagnostic for Report<'_> {
+    fn code<'b>(&'b self) -> Option<Box<dyn fmt::Display + 'b>> {
+        Some(Box::new(&self.diagnostic.rule))
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        Some(match self.diagnostic.severity {
+            Severity::Warning => miette::Severity::Warning,
+            Severity::Error => miette::Severity::Error,
+        })
+    }
+
+    fn help<'b>(&'b self) -> Option<Box<dyn fmt::Display + 'b>> {
+        self.diagnostic.help.as_ref().map(|h| Box::new(h) as Box<dyn fmt::Display>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(self.source)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let d = self.diagnostic;
+        let label = if d.fix_available { "fixable" } else { "here" };
+        Some(Box::new(std::iter::once(LabeledSpan::new_primary_with_span(
+            Some(label.to_string()),
+            d.start_offset..d.end_offset,
+        ))))
+    }
+}
+
+/// Renders each diagnostic as a code frame with the span underlined.
+pub struct Pretty {
+    pub color: bool,
+}
+
+impl Formatter for Pretty {
+    fn format(&self, results: &[LintResult]) -> String {
+        let theme = if self.color {
+            GraphicalTheme::unicode()
+        } else {
+            GraphicalTheme::unicode_nocolor()
+        };
+        let handler = GraphicalReportHandler::new_themed(theme);
+        let mut out = String::new();
+
+        for result in results {
+            if result.diagnostics.is_empty() {
+                continue;
+            }
+            let source = NamedSource::new(&result.path, Arc::<str>::from(result.source.as_str()));
+            // Rules report in registration order; read top to bottom instead
+            let mut diagnostics: Vec<_> = result.diagnostics.iter().collect();
+            diagnostics.sort_by_key(|d| d.start_offset);
+            for d in diagnostics {
+                let report = Report { diagnostic: d, source: &source };
+                if handler.render_report(&mut out, &report).is_ok() {
+                    out.push('\n');
+                }
+            }
+        }
+        out
+    }
+}
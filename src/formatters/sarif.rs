@@ -0,0 +1,62 @@
+use crate::formatters::Formatter;
+use crate::linter::{LintResult, Severity};
+use serde_json::json;
+use std::collections::BTreeSet;
+
+/// SARIF 2.1.0, for GitHub code scanning and other static analysis dashboards.
+pub struct Sarif;
+
+impl Formatter for Sarif {
+    fn format(&self, results: &[LintResult]) -> String {
+        let rule_ids: BTreeSet<&str> = results.iter()
+            .flat_map(|r| r.diagnostics.iter().map(|d| d.rule.as_str()))
+            .collect();
+        let rules: Vec<_> = rule_ids.iter().map(|id| json!({ "id": id })).collect();
+
+        let mut sarif_results = Vec::new();
+        for result in results {
+            for d in &result.diagnostics {
+                let level = match d.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                sarif_results.push(json!({
+                    "ruleId": d.rule,
+                    "ruleIndex": rule_ids.iter().position(|id| *id == d.rule).unwrap_or(0),
+                    "level": level,
+                    "message": { "text": d.message },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": result.path.replace('\\', "/") },
+                            "region": {
+                                "startLine": d.start_line,
+                                "startColumn": d.start_column,
+                                "endLine": d.end_line,
+                                "endColumn": d.end_column,
+                            },
+                        },
+                    }],
+                }));
+            }
+        }
+
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": env!("CARGO_PKG_NAME"),
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "columnKind": "utf16CodeUnits",
+                "results": sarif_results,
+            }],
+        });
+        let mut out = serde_json::to_string_pretty(&log).unwrap();
+        out.push('\n');
+        out
+    }
+}
@@ -0,0 +1,36 @@
+use crate::formatters::Formatter;
+use crate::linter::{LintResult, Severity};
+
+/// ESLint-style output: a header per file, then one aligned row per diagnostic.
+pub struct Stylish;
+
+impl Formatter for Stylish {
+    fn format(&self, results: &[LintResult]) -> String {
+        let mut out = String::new();
+        for result in results {
+            if result.diagnostics.is_empty() {
+                continue;
+            }
+            out.push_str(&result.path);
+            out.push('\n');
+
+            let mut diagnostics: Vec<_> = result.diagnostics.iter().collect();
+            diagnostics.sort_by_key(|d| d.start_offset);
+            let rows: Vec<(String, &str, &str, &str)> = diagnostics.into_iter().map(|d| {
+                let severity = match d.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                (format!("{}:{}", d.start_line, d.start_column), severity, d.message.as_str(), d.rule.as_str())
+            }).collect();
+            let loc_width = rows.iter().map(|r| r.0.len()).max().unwrap_or(0);
+            let msg_width = rows.iter().map(|r| r.2.chars().count()).max().unwrap_or(0);
+
+            for (loc, severity, message, rule) in rows {
+                out.push_str(&format!("  {:<loc_width$}  {:<7}  {:<msg_width$}  {}\n", loc, severity, message, rule));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
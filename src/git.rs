@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Which lines of a file changed.
+enum ChangedLines {
+    All, // New or untracked file
+    Ranges(Vec<RangeInclusive<usize>>),
+}
+
+/// Files (and lines) changed according to git, keyed by canonical path.
+pub struct ChangedFiles {
+    files: HashMap<PathBuf, ChangedLines>,
+}
+
+impl ChangedFiles {
+    /// Changes in the working tree against `since`, or in the index when `staged`.
+    pub fn from_git(since: Option<&str>, staged: bool) -> Result<Self, String> {
+        let root = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?.trim());
+
+        // Pin the path prefixes and root so `diff.noprefix`, `diff.mnemonicPrefix` and `diff.relative` don't change the headers
+        let mut diff_args = vec![
+            "diff", "-U0", "--no-color", "--no-ext-diff", "--diff-filter=ACMR",
+            "--src-prefix=a/", "--dst-prefix=b/", "--no-relative",
+        ];
+        if staged {
+            diff_args.push("--cached");
+        } else if let Some(since) = since {
+            diff_args.push(since);
+        }
+        diff_args.push("--");
+        let diff = git(&diff_args)?;
+
+        let mut files = HashMap::new();
+        let mut current: Option<PathBuf> = None;
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ").and_then(new_path) {
+                let path = canonical(&root.join(path));
+                files.insert(path.clone(), ChangedLines::Ranges(Vec::new()));
+                current = Some(path);
+            } else if line.starts_with("+++ ") {
+                current = None; // `+++ /dev/null`
+            } else if let (Some(hunk), Some(path)) = (line.strip_prefix("@@ "), &current) {
+                // `@@ -a,b +c,d @@`: lines c..c+d changed on the new side (d defaults to 1).
+                let Some(new_side) = hunk.split(' ').find(|part| part.starts_with('+')) else {
+                    continue;
+                };
+                let mut parts = new_side[1..].split(',');
+                let start: usize = parts.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                let count: usize = parts.next().and_then(|n| n.parse().ok()).unwrap_or(1);
+                if let (true, Some(ChangedLines::Ranges(ranges))) = (count > 0, files.get_mut(path)) {
+                    ranges.push(start..=start + count - 1);
+                }
+            }
+        }
+
+        // Untracked files are new relative to any ref
+        if !staged {
+            for path in git(&["ls-files", "-z", "--others", "--exclude-standard", "--full-name", ":/"])?.split_terminator('\0') {
+                files.insert(canonical(&root.join(path)), ChangedLines::All);
+            }
+        }
+
+        Ok(Self { files })
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        self.files.contains_key(&canonical(path))
+    }
+
+    pub fn contains_line(&self, path: &Path, line: usize) -> bool {
+        match self.files.get(&canonical(path)) {
+            Some(ChangedLines::All) => true,
+            Some(ChangedLines::Ranges(ranges)) => ranges.iter().any(|r| r.contains(&line)),
+            None => false,
+        }
+    }
+}
+
+/// The path in a `+++ b/<path>` header, or `None` for `+++ /dev/null`.
+/// Git adds a trailing tab after names with spaces, and C-quotes names with control characters, `"` or `\`.
+fn new_path(header: &str) -> Option<String> {
+    let header = header.strip_suffix('\t').unwrap_or(header);
+    let path = match header.strip_prefix('"').and_then(|h| h.strip_suffix('"')) {
+        Some(quoted) => unquote(quoted),
+        None => header.to_string(),
+    };
+    path.strip_prefix("b/").map(str::to_string)
+}
+
+fn unquote(quoted: &str) -> String {
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut rest = quoted.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        rest = tail;
+        if b != b'\\' {
+            bytes.push(b);
+            continue;
+        }
+        let Some((&escaped, tail)) = rest.split_first() else { break };
+        rest = tail;
+        bytes.push(match escaped {
+            b'a' => 0x07,
+            b'b' => 0x08,
+            b'f' => 0x0c,
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            b'v' => 0x0b,
+            // Three octal digits, one byte of a UTF-8 sequence
+            b'0'..=b'3' if rest.len() >= 2 => {
+                let (digits, tail) = rest.split_at(2);
+                rest = tail;
+                digits.iter().fold(escaped - b'0', |n, d| (n << 3) | (d.wrapping_sub(b'0') & 7))
+            }
+            other => other,
+        });
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(["-c", "core.quotePath=false"])
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("git {} failed: {}", args.join(" "), String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
@@ -0,0 +1,142 @@
+use crate::linter::{CustomBlock, SourceFile};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, ObjectPropertyKind};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+
+/// Locale messages from a component's `<i18n>` blocks. All spans are over the original file.
+///
+/// Blocks are either keyed by locale (`{ "en": {...}, "ja": {...} }`) or hold a single
+/// locale named by a `locale="en"` attribute. Blocks with a `src` or a non-JSON `lang` (YAML) are skipped;
+/// a non-JSON `lang` also gets an error, so the block isn't silently left unchecked.
+#[derive(Debug, Default)]
+pub struct Messages {
+    pub locales: Vec<LocaleMessages>,
+    pub errors: Vec<BlockError>,
+    pub unsupported: Vec<BlockError>, // Blocks in a language we can't read, like YAML
+    pub incomplete: bool, // Some block was skipped, so `locales` may lack keys the component has
+}
+
+#[derive(Debug)]
+pub struct LocaleMessages {
+    pub locale: String,
+    pub span: Span, // The locale key, or the start tag of a `locale="..."` block
+    pub keys: Vec<MessageKey>,
+}
+
+/// A leaf message, by its dotted path (`form.submit`).
+#[derive(Debug)]
+pub struct MessageKey {
+    pub path: String,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct BlockError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl Messages {
+    pub fn load(source_file: &SourceFile) -> Self {
+        let mut messages = Messages::default();
+        for block in source_file.custom_blocks.iter().filter(|b| b.tag == "i18n") {
+            if block.has_attribute("src") {
+                messages.incomplete = true;
+                continue;
+            }
+            if let Some(lang) = block.attribute("lang").filter(|lang| !matches!(*lang, "json" | "json5")) {
+                messages.incomplete = true;
+                messages.unsupported.push(BlockError {
+                    message: format!("<i18n lang=\"{}\"> blocks aren't supported; only JSON and JSON5 messages are checked.", lang),
+                    span: block.tag_span,
+                });
+                continue;
+            }
+            if block.content.trim().is_empty() {
+                continue;
+            }
+            match parse_block(block) {
+                Ok(locales) => {
+                    for locale in locales {
+                        match messages.locales.iter_mut().find(|l| l.locale == locale.locale) {
+                            Some(existing) => existing.keys.extend(locale.keys),
+                            None => messages.locales.push(locale),
+                        }
+                    }
+                }
+                Err(error) => messages.errors.push(error),
+            }
+        }
+        messages
+    }
+
+    /// Whether `path` names a message, or a group of messages, in `locale`.
+    pub fn has_key(locale: &LocaleMessages, path: &str) -> bool {
+        locale.keys.iter().any(|key| {
+            key.path == path || key.path.strip_prefix(path).is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+}
+
+fn parse_block(block: &CustomBlock) -> Result<Vec<LocaleMessages>, BlockError> {
+    // serde_json pinpoints syntax errors; the spans come from parsing the same text as a JS expression
+    if block.attribute("lang") != Some("json5")
+        && let Err(e) = serde_json::from_str::<serde_json::Value>(&block.content) {
+        let offset = line_column_offset(&block.content, e.line(), e.column());
+        // The position is relative to the block; the diagnostic carries the real one
+        let message = e.to_string();
+        let message = message.rsplit_once(" at line ").map_or(message.as_str(), |(message, _)| message);
+        return Err(BlockError {
+            message: format!("Invalid JSON in <i18n> block: {}", message),
+            span: block.span(Span::new(offset as u32, (offset + 1).min(block.content.len()) as u32)),
+        });
+    }
+
+    let allocator = Allocator::default();
+    let expression = Parser::new(&allocator, &block.content, SourceType::default())
+        .parse_expression()
+        .map_err(|errors| BlockError {
+            message: format!("Invalid JSON5 in <i18n> block: {}", errors.first().map(|e| e.to_string()).unwrap_or_default()),
+            span: block.tag_span,
+        })?;
+    let Expression::ObjectExpression(root) = expression.without_parentheses() else {
+        return Err(BlockError { message: "An <i18n> block must contain an object.".to_string(), span: block.tag_span });
+    };
+
+    if let Some(locale) = block.attribute("locale") {
+        let mut keys = Vec::new();
+        collect_keys(root, "", block, &mut keys);
+        return Ok(vec![LocaleMessages { locale: locale.to_string(), span: block.tag_span, keys }]);
+    }
+
+    let mut locales = Vec::new();
+    for property in &root.properties {
+        let ObjectPropertyKind::ObjectProperty(property) = property else { continue };
+        let Some(locale) = property.key.static_name() else { continue };
+        let mut keys = Vec::new();
+        if let Expression::ObjectExpression(object) = property.value.without_parentheses() {
+            collect_keys(object, "", block, &mut keys);
+        }
+        locales.push(LocaleMessages { locale: locale.to_string(), span: block.span(property.key.span()), keys });
+    }
+    Ok(locales)
+}
+
+fn collect_keys(object: &oxc_ast::ast::ObjectExpression, prefix: &str, block: &CustomBlock, keys: &mut Vec<MessageKey>) {
+    for property in &object.properties {
+        let ObjectPropertyKind::ObjectProperty(property) = property else { continue };
+        let Some(name) = property.key.static_name() else { continue };
+        let path = if prefix.is_empty() { name.to_string() } else { format!("{}.{}", prefix, name) };
+        match property.value.without_parentheses() {
+            Expression::ObjectExpression(nested) => collect_keys(nested, &path, block, keys),
+            _ => keys.push(MessageKey { path, span: block.span(property.key.span()) }),
+        }
+    }
+}
+
+/// Byte offset of a 1-based line / column pair (as serde_json reports them).
+fn line_column_offset(content: &str, line: usize, column: usize) -> usize {
+    let line_start = content.split_inclusive('\n').take(line.saturating_sub(1)).map(str::len).sum::<usize>();
+    (line_start + column.saturating_sub(1)).min(content.len().saturating_sub(1))
+}
@@ -0,0 +1,82 @@
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Paths excluded by `.linterignore`, config `ignorePatterns` and `--ignore-pattern`.
+/// All use .gitignore syntax, relative to `root` (the working directory, or the workspace for the language server).
+#[derive(Clone)]
+pub struct Ignores {
+    root: PathBuf,
+    matcher: Gitignore,
+}
+
+impl Ignores {
+    pub fn load(root: &Path, patterns: &[String], show_errors: bool) -> Self {
+        let root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+        let mut builder = GitignoreBuilder::new(&root);
+
+        let ignore_file = root.join(".linterignore");
+        let ignore_file_error = ignore_file.is_file().then(|| builder.add(&ignore_file)).flatten();
+        if let (Some(e), true) = (ignore_file_error, show_errors) {
+            eprintln!("Failed to read .linterignore: {}", e);
+        }
+        for pattern in patterns {
+            if let (Err(e), true) = (builder.add_line(None, pattern), show_errors) {
+                eprintln!("Invalid ignore pattern {}: {}", pattern, e);
+            }
+        }
+
+        let matcher = builder.build().unwrap_or_else(|e| {
+            if show_errors {
+                eprintln!("Failed to build ignore patterns: {}", e);
+            }
+            Gitignore::empty()
+        });
+        Self { root, matcher }
+    }
+
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if self.matcher.is_empty() {
+            return false;
+        }
+        // The matcher needs paths relative to its root; anything outside it is never ignored.
+        // Paths that don't exist (`--stdin-filename`) are taken relative to the root as written.
+        let abs = fs::canonicalize(path).unwrap_or_else(|_| self.root.join(path));
+        abs.strip_prefix(&self.root)
+            .is_ok_and(|relative| self.matcher.matched_path_or_any_parents(relative, is_dir).is_ignore())
+    }
+}
+
+/// `.gitignore` rules for single paths, so a changed file can be checked without walking its directory.
+/// Like the walker, only `.gitignore` files inside a git repository count. Each one is read once and kept.
+#[derive(Default)]
+pub struct GitIgnores {
+    matchers: HashMap<PathBuf, Option<Gitignore>>,
+}
+
+impl GitIgnores {
+    /// `path` must be absolute. The deepest `.gitignore` with a matching rule decides.
+    pub fn is_ignored(&mut self, path: &Path, is_dir: bool) -> bool {
+        for dir in path.ancestors().skip(1) {
+            let matcher = self.matchers.entry(dir.to_path_buf()).or_insert_with(|| {
+                let file = dir.join(".gitignore");
+                file.is_file().then(|| Gitignore::new(&file).0)
+            });
+            if let Some(matcher) = matcher {
+                let matched = matcher.matched_path_or_any_parents(path, is_dir);
+                if matched.is_ignore() || matched.is_whitelist() {
+                    return matched.is_ignore() && in_repository(dir);
+                }
+            }
+            if dir.join(".git").exists() {
+                break;
+            }
+        }
+        false
+    }
+}
+
+fn in_repository(dir: &Path) -> bool {
+    dir.ancestors().any(|d| d.join(".git").exists())
+}
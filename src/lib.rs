@@ -0,0 +1,25 @@
+//! Speedy Nuxt Linter as a library.
+//!
+//! Build a [`Linter`] from a [`LinterConfig`], register rules (the built-in ones from
+//! [`rules::builtin`] and/or your own [`Rule`] implementations), then call
+//! [`Linter::lint_source`] for in-memory content or [`Linter::lint_file`] for files on disk.
+
+pub mod bindings;
+pub mod css;
+pub mod directives;
+pub mod formatters;
+pub mod i18n;
+pub mod line_index;
+pub mod linter;
+pub mod rules;
+pub mod template_ast;
+pub mod template_parser;
+pub mod visitor;
+
+pub use linter::{
+    Context, CustomBlock, Diagnostic, DiagnosticWithLocation, Fix, FixFilter, LintResult, Linter, LinterConfig,
+    Rule, RuleCategory, RuleConfig, ScriptBlock, Severity, SourceFile, StyleBlock, Suggestion,
+};
+pub use bindings::Bindings;
+pub use template_parser::{tokenize, TemplateToken};
+pub use visitor::AstNode;
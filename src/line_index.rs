@@ -0,0 +1,56 @@
+/// Maps byte offsets to line/column positions.
+/// Built once per file; each lookup is a binary search over line starts.
+/// A leading byte order mark isn't a column: line 1 starts after it, like it does in editors.
+pub struct LineIndex<'a> {
+    content: &'a str,
+    line_starts: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineCol {
+    pub line: usize,         // 1-based
+    pub column: usize,       // 1-based, in UTF-16 code units (what editors and LSP count)
+    pub column_utf8: usize,  // 1-based, in UTF-8 bytes
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(content: &'a str) -> Self {
+        let mut line_starts = vec![if content.starts_with('\u{FEFF}') { '\u{FEFF}'.len_utf8() } else { 0 }];
+        line_starts.extend(content.match_indices('\n').map(|(i, _)| i + 1));
+        Self { content, line_starts }
+    }
+
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        // Clamp into the content (past any BOM) and back onto a char boundary.
+        let mut offset = offset.clamp(self.line_starts[0], self.content.len());
+        while !self.content.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        LineCol {
+            line: line + 1,
+            column: self.content[line_start..offset].encode_utf16().count() + 1,
+            column_utf8: offset - line_start + 1,
+        }
+    }
+
+    /// Inverse of `line_col`: 1-based line and UTF-16 column to a byte offset.
+    /// Positions past the end of a line clamp to the line end (before the `\n` or `\r\n`).
+    pub fn offset(&self, line: usize, column: usize) -> usize {
+        let Some(&line_start) = self.line_starts.get(line.saturating_sub(1)) else {
+            return self.content.len();
+        };
+        let line_end = self.line_starts.get(line).copied().unwrap_or(self.content.len());
+        let line_text = &self.content[line_start..line_end];
+        let mut units = 1;
+        for (i, c) in line_text.char_indices() {
+            if units >= column || c == '\n' || line_text[i..].starts_with("\r\n") {
+                return line_start + i;
+            }
+            units += c.len_utf16();
+        }
+        line_end
+    }
+}
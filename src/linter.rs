@@ -1,22 +1,48 @@
 use oxc_allocator::Allocator;
 use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{SourceType, Span};
 use std::path::{Path, PathBuf};
 use std::fs; // fix 1: import fs
-use html5gum::Tokenizer;
-use crate::template_parser::{SpannedEmitter, TemplateToken};
+use std::io::{self, Write};
+use crate::template_parser::{tokenize, tokenize_with_errors, TemplateToken};
+use crate::line_index::LineIndex;
+use crate::directives::DisableDirectives;
+use crate::visitor::{run_node_rules, AstNode};
+use crate::template_ast::{self, TemplateNode};
+use crate::bindings::Bindings;
+use crate::i18n::Messages;
+use crate::css::{self, CssToken};
+use std::cell::OnceCell;
+use oxc_ast::AstType;
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 
 // --- Data Structures ---
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinterConfig {
     #[serde(default)]
     pub rules: HashMap<String, RuleConfig>,
+    /// File extensions to lint when walking directories. `vue` files are SFCs; the rest are plain scripts.
+    #[serde(default = "default_extensions")]
+    pub extensions: Vec<String>,
+    /// Extra paths to skip, in .gitignore syntax (on top of `.gitignore` and `.linterignore`).
+    #[serde(default, rename = "ignorePatterns")]
+    pub ignore_patterns: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl Default for LinterConfig {
+    fn default() -> Self {
+        Self { rules: HashMap::new(), extensions: default_extensions(), ignore_patterns: Vec::new() }
+    }
+}
+
+fn default_extensions() -> Vec<String> {
+    ["vue", "js", "mjs", "cjs", "ts", "mts", "cts"].iter().map(|ext| ext.to_string()).collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum RuleConfig {
     State(String), // "off", "warn", "error"
@@ -29,23 +55,88 @@ impl Default for RuleConfig {
     }
 }
 
+impl LinterConfig {
+    /// Loads `.linterrc.json`-style config. A missing file means the default config.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        if fs::metadata(path).is_err() {
+            return Ok(LinterConfig::default());
+        }
+        let content = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    }
+
+    /// Whether a walked file should be linted, judging by its extension.
+    pub fn is_lintable(&self, path: &Path) -> bool {
+        path.extension().is_some_and(|ext| self.extensions.iter().any(|e| ext == e.trim_start_matches('.')))
+    }
+}
+
 impl RuleConfig {
     pub fn is_enabled(&self) -> bool {
         match self {
             RuleConfig::State(s) => s != "off",
         }
     }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            RuleConfig::State(s) if s == "warn" => Severity::Warning,
+            RuleConfig::State(_) => Severity::Error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
 }
 
 #[derive(Debug, Clone)]
 pub struct SourceFile {
     pub path: PathBuf,
     pub original_content: String,
-    #[allow(dead_code)]
     pub script_content: String,
     pub script_start_offset: usize, // Start position of script in original file
+    pub script_setup: bool,         // `<script setup>`
+    /// The plain `<script>` of an SFC that also has a `<script setup>` (which is the main script above).
+    pub other_script: Option<ScriptBlock>,
     pub template_content: String,       // New
     pub template_start_offset: usize,   // New
+    pub styles: Vec<StyleBlock>,
+    pub custom_blocks: Vec<CustomBlock>,
+}
+
+/// A `<script>` block of an SFC.
+#[derive(Debug, Clone)]
+pub struct ScriptBlock {
+    pub content: String,
+    pub start_offset: usize, // Start position of the content in the original file
+    pub setup: bool,
+}
+
+/// A `<style>` block of an SFC.
+#[derive(Debug, Clone)]
+pub struct StyleBlock {
+    pub content: String,
+    pub start_offset: usize,  // Start position of the content in the original file
+    pub lang: Option<String>, // `scss`, `less`, ...; `None` for plain CSS
+    pub scoped: bool,
+    pub module: bool,         // `module` or `module="name"`
+}
+
+impl StyleBlock {
+    pub fn tokenize(&self) -> Vec<CssToken> {
+        let line_comments = self.lang.as_deref().is_some_and(|lang| matches!(lang, "scss" | "sass" | "less" | "stylus" | "styl"));
+        css::tokenize(&self.content, line_comments)
+    }
+
+    /// Converts a span from the block's tokens into one over the original file.
+    pub fn span(&self, span: Span) -> Span {
+        let offset = self.start_offset as u32;
+        Span::new(span.start + offset, span.end + offset)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -53,6 +144,7 @@ pub struct Diagnostic {
     pub message: String,
     pub span: Span,         // Span relative to script_content OR template_content
     pub fix: Option<Fix>,
+    pub suggestions: Vec<Suggestion>, // Never applied by `--fix`; offered to the user instead
 }
 
 #[derive(Debug, Clone)]
@@ -61,11 +153,81 @@ pub struct Fix {
     pub replacement: String,
 }
 
+/// An alternative change the user may apply by hand (or via an editor code action).
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub message: String,
+    pub edits: Vec<Fix>,
+}
+
 
 pub struct Context<'a> {
     pub source_file: &'a SourceFile,
     pub program: &'a oxc_ast::ast::Program<'a>,
+    pub semantic: &'a Semantic<'a>, // Scopes, symbols and resolved references of the script
     pub template_tokens: Option<&'a Vec<TemplateToken>>, // Updated
+    pub template: Option<&'a [TemplateNode]>, // Element tree built from the tokens
+    pub script_start_offset: usize, // Where `program` starts in the original file
+    bindings: OnceCell<Bindings>,
+    i18n: OnceCell<Messages>,
+}
+
+impl Context<'_> {
+    /// Whether `ident` refers to a global (no declaration or import of that name is in scope).
+    pub fn is_global_reference(&self, ident: &oxc_ast::ast::IdentifierReference) -> bool {
+        // Resolve this reference specifically; `Semantic::is_reference_to_global_variable` only
+        // checks whether the name is unresolved *somewhere* in the file.
+        ident.reference_id.get()
+            .is_none_or(|id| self.semantic.scoping().get_reference(id).symbol_id().is_none())
+    }
+
+    /// Converts a span from the script AST into one over the original file.
+    pub fn script_span(&self, span: Span) -> Span {
+        let offset = self.script_start_offset as u32;
+        Span::new(span.start + offset, span.end + offset)
+    }
+
+    /// Converts a span from the template tree (or tokens) into one over the original file.
+    pub fn template_span(&self, span: Span) -> Span {
+        let offset = self.source_file.template_start_offset as u32;
+        Span::new(span.start + offset, span.end + offset)
+    }
+
+    /// Which script bindings the template uses. Computed on first use and shared by all rules.
+    pub fn bindings(&self) -> &Bindings {
+        self.bindings.get_or_init(|| Bindings::analyze(self))
+    }
+
+    /// Locale messages from the `<i18n>` blocks. Parsed on first use and shared by all rules.
+    pub fn i18n(&self) -> &Messages {
+        self.i18n.get_or_init(|| Messages::load(self.source_file))
+    }
+}
+
+/// A top-level SFC block that isn't `<template>`, `<script>` or `<style>`, e.g. `<i18n>`.
+#[derive(Debug, Clone)]
+pub struct CustomBlock {
+    pub tag: String,
+    pub attributes: Vec<(String, Option<String>)>,
+    pub content: String,
+    pub start_offset: usize, // Start position of the content in the original file
+    pub tag_span: Span,      // The start tag, in the original file
+}
+
+impl CustomBlock {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(n, _)| n == name).and_then(|(_, value)| value.as_deref())
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attributes.iter().any(|(n, _)| n == name)
+    }
+
+    /// Converts a span over the block content into one over the original file.
+    pub fn span(&self, span: Span) -> Span {
+        let offset = self.start_offset as u32;
+        Span::new(span.start + offset, span.end + offset)
+    }
 }
 
 // Output structure
@@ -74,22 +236,82 @@ pub struct LintResult {
     pub path: String,
     pub diagnostics: Vec<DiagnosticWithLocation>,
     pub fixed_count: usize,
+    #[serde(skip)]
+    pub source: String, // Original content, for code frames
+    #[serde(skip)]
+    pub fixed_source: Option<String>, // Content after fixes, when any were applied
+    #[serde(skip)]
+    pub fix_error: Option<String>, // Why fixes were refused, naming the rules whose fixes break parsing
 }
 
 #[derive(Debug, Serialize)]
 pub struct DiagnosticWithLocation {
+    pub rule: String,
+    pub severity: Severity,
     pub message: String,
     pub start_line: usize,
-    pub start_column: usize,
+    pub start_column: usize, // UTF-16 code units, as editors count
     pub end_line: usize,
     pub end_column: usize,
+    pub start_column_utf8: usize, // UTF-8 bytes
+    pub end_column_utf8: usize,
+    pub start_offset: usize, // Byte offsets into the original content
+    pub end_offset: usize,
     pub fix_available: bool,
+    pub help: Option<String>,
+    #[serde(skip)]
+    pub fix: Option<Fix>,
+    #[serde(skip)]
+    pub suggestions: Vec<Suggestion>,
 }
 
+/// What kind of change a rule's fix makes. Used by `--fix-type` to pick which fixes to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RuleCategory {
+    /// Code that is likely wrong or unsafe.
+    Problem,
+    /// Semantic rewrites that change what the code does (e.g. `process.env` -> `import.meta.env`).
+    Suggestion,
+    /// Formatting only; never changes behavior.
+    Style,
+}
+
+/// Limits which diagnostics' fixes are applied. Empty lists mean "no restriction".
+#[derive(Debug, Clone, Default)]
+pub struct FixFilter {
+    pub rules: Vec<String>,
+    pub categories: Vec<RuleCategory>,
+}
+
+impl FixFilter {
+    pub fn allows(&self, rule_name: &str, category: RuleCategory) -> bool {
+        (self.rules.is_empty() || self.rules.iter().any(|r| r == rule_name))
+            && (self.categories.is_empty() || self.categories.contains(&category))
+    }
+}
+
+/// A lint rule. Script rules list the node types they care about in `node_types` and get
+/// `run_on_node` calls from the engine's single AST traversal; whole-file checks (e.g. the template) go in `run`.
 pub trait Rule: Send + Sync { // fix 2: Add Send + Sync
-    #[allow(dead_code)]
     fn name(&self) -> &'static str;
-    fn run(&self, ctx: &Context) -> Vec<Diagnostic>;
+    fn category(&self) -> RuleCategory;
+
+    /// Severity when the config doesn't set one.
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn run(&self, _ctx: &Context) -> Vec<Diagnostic> {
+        Vec::new()
+    }
+
+    fn node_types(&self) -> &'static [AstType] {
+        &[]
+    }
+
+    fn run_on_node(&self, _node: &AstNode, _ctx: &Context) -> Vec<Diagnostic> {
+        Vec::new()
+    }
 }
 
 // --- Linter Engine ---
@@ -97,20 +319,44 @@ pub trait Rule: Send + Sync { // fix 2: Add Send + Sync
 pub struct Linter {
     rules: Vec<Box<dyn Rule>>,
     config: LinterConfig,
+    fix_filter: FixFilter,
 }
 
 impl Linter {
     pub fn new(config: LinterConfig) -> Self {
-        Self { rules: Vec::new(), config }
+        Self { rules: Vec::new(), config, fix_filter: FixFilter::default() }
     }
 
     pub fn add_rule(&mut self, rule: Box<dyn Rule>) {
         self.rules.push(rule);
     }
 
+    pub fn set_fix_filter(&mut self, fix_filter: FixFilter) {
+        self.fix_filter = fix_filter;
+    }
+
+    pub fn config(&self) -> &LinterConfig {
+        &self.config
+    }
+
+    pub fn is_lintable(&self, path: &Path) -> bool {
+        self.config.is_lintable(path)
+    }
+
+    fn rule_severity(&self, rule_name: &str) -> Severity {
+        self.config.rules.get(rule_name).map_or_else(
+            || self.rules.iter().find(|r| r.name() == rule_name).map_or(Severity::Error, |r| r.default_severity()),
+            |conf| conf.severity(),
+        )
+    }
+
+    fn is_fix_allowed(&self, rule_name: &str) -> bool {
+        self.rules.iter()
+            .find(|r| r.name() == rule_name)
+            .is_some_and(|r| self.fix_filter.allows(rule_name, r.category()))
+    }
+
     pub fn lint_file(&self, path: &Path, fix: bool) -> Option<LintResult> {
-        let allocator = Allocator::default();
-        
         // Read file
         let original_content = match fs::read_to_string(path) {
             Ok(c) => c,
@@ -119,168 +365,521 @@ impl Linter {
                 return None;
             }
         };
-            
-        // 1. SFC Parsing (Extract Script & Template)
-        // We use html5gum to scan tokens and find script/template blocks.
-        let mut script_content = String::new();
-        let mut script_start_offset = 0;
-        let mut template_content = String::new();
-        let mut template_start_offset = 0;
-
-        // Script extraction
-        if let Some(start_tag_idx) = original_content.find("<script") {
-             if let Some(script_content_start) = original_content[start_tag_idx..].find('>') {
-                 let actual_start = start_tag_idx + script_content_start + 1;
-                 // Find closing tag strictly after start
-                 if let Some(end_tag_relative) = original_content[actual_start..].find("</script>") {
-                     script_start_offset = actual_start;
-                     script_content = original_content[actual_start .. actual_start + end_tag_relative].to_string();
-                 }
-             }
-        } else {
-             // Fallback: entire file is script (if no template/style)? No, SFC requires <script>.
-             // If no script tag, try to parse as script? No, assume empty script.
-        }
 
-        // Template extraction (simple)
-        if let Some(start_tag_idx) = original_content.find("<template") {
-            if let Some(content_start) = original_content[start_tag_idx..].find('>') {
-                 let actual_start = start_tag_idx + content_start + 1;
-                 if let Some(end_tag_relative) = original_content[actual_start..].find("</template>") {
-                    template_start_offset = actual_start;
-                    template_content = original_content[actual_start .. actual_start + end_tag_relative].to_string();
-                 }
-            }
+        let mut result = self.lint_source(path, &original_content, fix);
+
+        let write_error = result.fixed_source.as_ref().and_then(|fixed| write_atomic(path, fixed).err());
+        if let Some(e) = write_error {
+            eprintln!("Failed to write fix: {}", e);
+            result.fixed_count = 0;
         }
 
-        let source_file = SourceFile {
-            path: path.to_path_buf(),
-            original_content: original_content.clone(),
-            script_content: script_content.clone(),
-            script_start_offset,
-            template_content,
-            template_start_offset,
-        };
+        Some(result)
+    }
+
+    /// Lints in-memory content as if it lived at `path`. Never touches the filesystem;
+    /// with `fix`, the fixed content is returned in `LintResult::fixed_source`.
+    pub fn lint_source(&self, path: &Path, content: &str, fix: bool) -> LintResult {
+        let allocator = Allocator::default();
+        let original_content = content.to_string();
+
+        // 1. SFC Parsing (Extract Script & Template)
+        let source_file = extract_sfc(path, original_content.clone());
 
         // 2. Parse Script
-        let source_type = SourceType::from_path(path).unwrap_or_default().with_typescript(true);
-        let ret = Parser::new(&allocator, &script_content, source_type).parse();
+        let ret = Parser::new(&allocator, &source_file.script_content, source_type(path)).parse();
 
         // 3. Parse Template (Tokenize)
         let template_tokens = if !source_file.template_content.is_empty() {
-             let (emitter, _) = SpannedEmitter::new(&source_file.template_content);
-             let tokenizer = Tokenizer::new_with_emitter(&source_file.template_content, emitter);
-             // We need to collect tokens AND update offsets.
-             // Our SpannedEmitter produces TemplateToken with spans relative to *template_content*.
-             // The Linter (or Rule) adds `template_start_offset` later.
-             // Wait, SpannedEmitter is used inside `Tokenizer`.
-             // Collect tokens from the iterator (this drains the emitter via pop_token)
-             let tokens: Vec<TemplateToken> = tokenizer.filter_map(|res| res.ok()).collect();
-             Some(tokens)
+            Some(tokenize(&source_file.template_content).0)
         } else {
             None
         };
+        // The plain `<script>` next to a `<script setup>` is linted by the node rules too
+        let other_ret = source_file.other_script.as_ref()
+            .map(|script| Parser::new(&allocator, &script.content, source_type(path)).parse());
+
+        let template = template_tokens.as_ref().map(|tokens| template_ast::build(&source_file.template_content, tokens));
+
+        // Resolve scopes and references once, for all rules
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
 
         let ctx = Context {
             source_file: &source_file,
             program: &ret.program,
+            semantic: &semantic,
             template_tokens: template_tokens.as_ref(), // Pass reference
+            template: template.as_deref(),
+            script_start_offset: source_file.script_start_offset,
+            bindings: OnceCell::new(),
+            i18n: OnceCell::new(),
         };
 
         // 4. Run Rules
-        let mut diagnostics = Vec::new();
-        for rule in &self.rules {
-            let rule_name = rule.name();
-            // Check config
-            let is_enabled = if let Some(conf) = self.config.rules.get(rule_name) {
-                conf.is_enabled()
-            } else {
-                true // Enabled by default if not in config
+        // Each diagnostic is tagged with the rule that produced it.
+        let enabled_rules: Vec<&dyn Rule> = self.rules.iter()
+            .filter(|rule| self.config.rules.get(rule.name()).is_none_or(|conf| conf.is_enabled())) // Enabled by default if not in config
+            .map(|rule| rule.as_ref())
+            .collect();
+
+        let mut diagnostics: Vec<(&'static str, Diagnostic)> = Vec::new();
+        for rule in &enabled_rules {
+            diagnostics.extend(rule.run(&ctx).into_iter().map(|d| (rule.name(), d)));
+        }
+        // Node rules share one walk over the script AST
+        diagnostics.extend(run_node_rules(&enabled_rules, &ctx));
+        if let (Some(script), Some(other_ret)) = (&source_file.other_script, &other_ret) {
+            let other_semantic = SemanticBuilder::new().build(&other_ret.program).semantic;
+            let other_ctx = Context {
+                source_file: &source_file,
+                program: &other_ret.program,
+                semantic: &other_semantic,
+                template_tokens: None,
+                template: None,
+                script_start_offset: script.start_offset,
+                bindings: OnceCell::new(),
+                i18n: OnceCell::new(),
             };
-
-            if is_enabled {
-                diagnostics.extend(rule.run(&ctx));
-            }
+            diagnostics.extend(run_node_rules(&enabled_rules, &other_ctx));
         }
 
+        // Drop diagnostics silenced by `linter-disable-*` comments (before fixing, so they aren't fixed either)
+        let line_index = LineIndex::new(&original_content);
+        let directives = DisableDirectives::parse(&original_content, &line_index);
+        diagnostics.retain(|(rule_name, d)| {
+            !directives.is_disabled(rule_name, line_index.line_col(d.span.start as usize).line)
+        });
+
         // 5. Apply Fixes (if enabled)
-        let mut fixed_count = 0;
-        if fix {
-            fixed_count = self.apply_fixes(&source_file, &diagnostics);
-        }
+        let (fixed_source, fixed_count, fix_error) = match fix.then(|| self.apply_fixes(&source_file, &diagnostics)) {
+            Some(Ok(Some((fixed_source, fixed_count)))) => (Some(fixed_source), fixed_count, None),
+            Some(Err(e)) => {
+                eprintln!("Refusing to apply fixes to {}: {}", path.display(), e);
+                (None, 0, Some(e))
+            }
+            _ => (None, 0, None),
+        };
 
         // 6. Enrich Diagnostics
-        let enriched_diagnostics = diagnostics.into_iter().map(|d| {
+        let enriched_diagnostics = diagnostics.into_iter().map(|(rule_name, d)| {
             let abs_start = d.span.start as usize; // Rules now return absolute spans
             let abs_end = d.span.end as usize;
-            let (start_line, start_column) = get_line_col(&original_content, abs_start);
-            let (end_line, end_column) = get_line_col(&original_content, abs_end);
-            
+            let start = line_index.line_col(abs_start);
+            let end = line_index.line_col(abs_end);
+            let help = d.fix.as_ref().map(|f| {
+                if f.replacement.is_empty() {
+                    "Run with `--fix` to remove this code.".to_string()
+                } else {
+                    format!("Run with `--fix` to replace with `{}`.", f.replacement)
+                }
+            });
+
             DiagnosticWithLocation {
+                rule: rule_name.to_string(),
+                severity: self.rule_severity(rule_name),
                 message: d.message,
-                start_line,
-                start_column,
-                end_line,
-                end_column,
+                start_line: start.line,
+                start_column: start.column,
+                end_line: end.line,
+                end_column: end.column,
+                start_column_utf8: start.column_utf8,
+                end_column_utf8: end.column_utf8,
+                start_offset: abs_start,
+                end_offset: abs_end,
                 fix_available: d.fix.is_some(),
+                help,
+                fix: d.fix,
+                suggestions: d.suggestions,
             }
         }).collect();
 
-        Some(LintResult {
+        LintResult {
             path: path.to_string_lossy().to_string(),
             diagnostics: enriched_diagnostics,
             fixed_count,
-        })
+            source: original_content,
+            fixed_source,
+            fix_error,
+        }
     }
 
-    fn apply_fixes(&self, source_file: &SourceFile, diagnostics: &[Diagnostic]) -> usize {
-        let mut fixes: Vec<&Fix> = diagnostics.iter().filter_map(|d| d.fix.as_ref()).collect();
-        
+    /// Returns the fixed content and the number of fixes applied, or `None` if there was nothing to fix.
+    /// Fixes that would add a parse error are refused; the error names the rules responsible.
+    fn apply_fixes(&self, source_file: &SourceFile, diagnostics: &[(&'static str, Diagnostic)]) -> Result<Option<(String, usize)>, String> {
+        let fixes: Vec<(&'static str, &Fix)> = diagnostics.iter()
+            .filter(|(rule_name, _)| self.is_fix_allowed(rule_name))
+            .filter_map(|(rule_name, d)| d.fix.as_ref().map(|f| (*rule_name, f)))
+            .collect();
+
         if fixes.is_empty() {
-            return 0;
+            return Ok(None);
         }
 
-        // Sort fixes by start position descending
-        fixes.sort_by(|a, b| b.span.start.cmp(&a.span.start));
-
         let fix_count = fixes.len();
-        let mut new_content = source_file.original_content.clone();
-
-        for fix in fixes {
-            let abs_start = fix.span.start as usize;
-            let abs_end = fix.span.end as usize;
-            
-            // Check boundaries
-            if abs_start > new_content.len() || abs_end > new_content.len() {
-                continue;
+        let (new_content, replaced) = splice_fixes(&source_file.original_content, &fixes);
+
+        // Re-parse the fixed content. A fix may move existing parse errors around, but never add one.
+        let path = &source_file.path;
+        let mut original_errors = None;
+        let mut breaks_parsing = |content: &str, replaced: &[Replaced]| {
+            let errors = parse_errors(path, content);
+            !errors.is_empty() && introduces_errors(original_errors.get_or_insert_with(|| parse_errors(path, &source_file.original_content)), errors, replaced)
+        };
+        if breaks_parsing(&new_content, &replaced) {
+            // Find out which rule(s) are responsible by applying each rule's fixes on their own.
+            let mut rule_names: Vec<&'static str> = fixes.iter().map(|(name, _)| *name).collect();
+            rule_names.sort();
+            rule_names.dedup();
+
+            let offenders: Vec<&'static str> = rule_names.into_iter().filter(|name| {
+                let own: Vec<(&'static str, &Fix)> = fixes.iter().filter(|(n, _)| n == name).copied().collect();
+                let (content, replaced) = splice_fixes(&source_file.original_content, &own);
+                breaks_parsing(&content, &replaced)
+            }).collect();
+
+            return Err(if offenders.is_empty() {
+                "combined fixes introduce parse errors".to_string()
+            } else {
+                format!("fixes from {} introduce parse errors", offenders.join(", "))
+            });
+        }
+
+        Ok(Some((new_content, fix_count)))
+    }
+}
+
+/// Extracts the `<script>` and `<template>` blocks of an SFC.
+/// Anything that isn't a `.vue` file is a plain script: the whole content is the script block.
+pub fn extract_sfc(path: &Path, original_content: String) -> SourceFile {
+    if !is_sfc(path) {
+        return SourceFile {
+            path: path.to_path_buf(),
+            script_content: original_content.clone(),
+            original_content,
+            script_start_offset: 0,
+            script_setup: false,
+            other_script: None,
+            template_content: String::new(),
+            template_start_offset: 0,
+            styles: Vec::new(),
+            custom_blocks: Vec::new(),
+        };
+    }
+
+    let mut template_content = String::new();
+    let mut template_start_offset = 0;
+
+    // Script extraction. An SFC may have a `<script>` next to its `<script setup>`; the setup one is
+    // the main script (it's what the template sees), the other is kept on the side.
+    let mut scripts = extract_scripts(&original_content);
+    let main_index = scripts.iter().position(|s| s.setup).unwrap_or(0);
+    let main_script = (!scripts.is_empty()).then(|| scripts.remove(main_index));
+    let other_script = scripts.into_iter().next();
+    let (script_content, script_start_offset, script_setup) = match main_script {
+        Some(script) => (script.content, script.start_offset, script.setup),
+        None => (String::new(), 0, false), // No script block: an empty script
+    };
+
+    // Template extraction (simple)
+    if let Some(start_tag_idx) = original_content.find("<template")
+        && let Some(content_start) = original_content[start_tag_idx..].find('>') {
+             let actual_start = start_tag_idx + content_start + 1;
+             if let Some(end_tag_relative) = find_template_end(&original_content[actual_start..]) {
+                template_start_offset = actual_start;
+                template_content = original_content[actual_start .. actual_start + end_tag_relative].to_string();
+             }
+    }
+
+    let styles = extract_styles(&original_content);
+    let custom_blocks = extract_custom_blocks(&original_content);
+
+    SourceFile {
+        path: path.to_path_buf(),
+        original_content,
+        script_content,
+        script_start_offset,
+        script_setup,
+        other_script,
+        template_content,
+        template_start_offset,
+        styles,
+        custom_blocks,
+    }
+}
+
+/// Every `<script>` block, in file order.
+fn extract_scripts(content: &str) -> Vec<ScriptBlock> {
+    let mut scripts = Vec::new();
+    let mut pos = 0;
+    while let Some(tag_start) = content[pos..].find("<script").map(|i| pos + i) {
+        pos = tag_start + "<script".len();
+        if !content[pos..].starts_with(|c: char| c.is_whitespace() || c == '>') {
+            continue;
+        }
+        let Some(tag_end) = content[pos..].find('>').map(|i| pos + i) else { break };
+        let Some(end) = content[tag_end + 1..].find("</script>").map(|i| tag_end + 1 + i) else { break };
+
+        let setup = block_attributes(&content[pos..tag_end]).iter().any(|(name, _)| name == "setup");
+        scripts.push(ScriptBlock { content: content[tag_end + 1..end].to_string(), start_offset: tag_end + 1, setup });
+        pos = end + "</script>".len();
+    }
+    scripts
+}
+
+/// Every `<style>` block, in file order.
+fn extract_styles(content: &str) -> Vec<StyleBlock> {
+    let mut styles = Vec::new();
+    let mut pos = 0;
+    while let Some(tag_start) = content[pos..].find("<style").map(|i| pos + i) {
+        pos = tag_start + "<style".len();
+        if !content[pos..].starts_with(|c: char| c.is_whitespace() || c == '>') {
+            continue;
+        }
+        let Some(tag_end) = content[pos..].find('>').map(|i| pos + i) else { break };
+        let Some(end) = content[tag_end + 1..].find("</style>").map(|i| tag_end + 1 + i) else { break };
+
+        let mut block = StyleBlock { content: content[tag_end + 1..end].to_string(), start_offset: tag_end + 1, lang: None, scoped: false, module: false };
+        for (name, value) in block_attributes(&content[pos..tag_end]) {
+            match name.as_str() {
+                "lang" => block.lang = value,
+                "scoped" => block.scoped = true,
+                "module" => block.module = true,
+                _ => {}
             }
+        }
+        styles.push(block);
+        pos = end + "</style>".len();
+    }
+    styles
+}
 
-            new_content.replace_range(abs_start..abs_end, &fix.replacement);
+/// Top-level blocks other than `<template>`, `<script>` and `<style>`, in file order.
+fn extract_custom_blocks(content: &str) -> Vec<CustomBlock> {
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+    while let Some(tag_start) = content[pos..].find('<').map(|i| pos + i) {
+        if content[tag_start..].starts_with("<!--") {
+            pos = content[tag_start..].find("-->").map_or(content.len(), |i| tag_start + i + 3);
+            continue;
+        }
+        let name_end = content[tag_start + 1..]
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .map_or(content.len(), |i| tag_start + 1 + i);
+        let tag = &content[tag_start + 1..name_end];
+        let Some(tag_end) = content[name_end..].find('>').map(|i| name_end + i) else { break };
+        if tag.is_empty() || !tag.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            pos = tag_start + 1;
+            continue;
         }
 
-        if let Err(e) = std::fs::write(&source_file.path, new_content) {
-            eprintln!("Failed to write fix: {}", e);
-            0
+        // `(content end, position after the block)`
+        let close = format!("</{}>", tag);
+        let bounds = if content[..tag_end].ends_with('/') {
+            Some((tag_end + 1, tag_end + 1)) // e.g. `<i18n src="./messages.json" />`
+        } else if tag == "template" {
+            find_template_end(&content[tag_end + 1..]).map(|i| (tag_end + 1 + i, tag_end + 1 + i + close.len()))
         } else {
-            fix_count
+            content[tag_end + 1..].find(&close).map(|i| (tag_end + 1 + i, tag_end + 1 + i + close.len()))
+        };
+        let Some((end, next)) = bounds else { break };
+
+        if !matches!(tag, "template" | "script" | "style") {
+            blocks.push(CustomBlock {
+                tag: tag.to_string(),
+                attributes: block_attributes(&content[name_end..tag_end]),
+                content: content[tag_end + 1..end].to_string(),
+                start_offset: tag_end + 1,
+                tag_span: Span::new(tag_start as u32, tag_end as u32 + 1),
+            });
+        }
+        pos = next;
+    }
+    blocks
+}
+
+/// Attributes of a block's start tag (the text between the tag name and `>`). Values are unquoted.
+fn block_attributes(tag: &str) -> Vec<(String, Option<String>)> {
+    tag.trim_end_matches('/').split_whitespace().map(|attr| match attr.split_once('=') {
+        Some((name, value)) => (name.to_string(), Some(value.trim_matches(|c| c == '"' || c == '\'').to_string())),
+        None => (attr.to_string(), None),
+    }).collect()
+}
+
+/// Finds the `</template>` closing the root block, skipping nested `<template>` elements (slots, `v-if` groups).
+fn find_template_end(content: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut pos = 0;
+    loop {
+        let close = pos + content[pos..].find("</template>")?;
+        let open = content[pos..close].match_indices("<template")
+            .map(|(i, _)| pos + i)
+            .find(|&i| content[i + "<template".len()..].starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/'));
+        match open {
+            Some(open) => {
+                depth += 1;
+                pos = open + "<template".len();
+            }
+            None if depth == 0 => return Some(close),
+            None => {
+                depth -= 1;
+                pos = close + "</template>".len();
+            }
         }
     }
 }
 
-fn get_line_col(content: &str, offset: usize) -> (usize, usize) {
-    let mut line = 1;
-    let mut col = 1;
-    for (i, c) in content.char_indices() {
-        if i == offset {
+fn is_sfc(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "vue")
+}
+
+/// SFC scripts are parsed as TypeScript (a superset of JS); plain scripts by their extension.
+fn source_type(path: &Path) -> SourceType {
+    if is_sfc(path) {
+        SourceType::default().with_typescript(true)
+    } else {
+        SourceType::from_path(path).unwrap_or_default()
+    }
+}
+
+/// A parse error, for telling the errors a fix adds from the ones a file already has.
+/// Script errors carry their span in the file; html5gum reports no positions, so template errors don't.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct ParseError {
+    message: String,
+    span: Option<(usize, usize)>,
+}
+
+/// Runs SFC extraction plus the script and template parsers, and collects the errors.
+fn parse_errors(path: &Path, content: &str) -> Vec<ParseError> {
+    let source_file = extract_sfc(path, content.to_string());
+    let allocator = Allocator::default();
+    let scripts = std::iter::once((source_file.script_content.as_str(), source_file.script_start_offset))
+        .chain(source_file.other_script.iter().map(|script| (script.content.as_str(), script.start_offset)));
+
+    let mut errors = Vec::new();
+    for (script, offset) in scripts {
+        for error in Parser::new(&allocator, script, source_type(path)).parse().errors {
+            let span = error.labels.as_ref().and_then(|labels| labels.first())
+                .map(|label| (offset + label.offset(), offset + label.offset() + label.len()));
+            errors.push(ParseError { message: error.message.to_string(), span });
+        }
+    }
+    if !source_file.template_content.is_empty() {
+        let template_errors = tokenize_with_errors(&source_file.template_content).1;
+        errors.extend(template_errors.into_iter().map(|error| ParseError { message: error.to_string(), span: None }));
+    }
+    errors
+}
+
+/// Whether `fixed` has errors the original didn't. Spans are mapped back through `replaced`, so an
+/// error that only moved isn't new; one that touches replaced text always is.
+fn introduces_errors(original: &[ParseError], fixed: Vec<ParseError>, replaced: &[Replaced]) -> bool {
+    let mut remaining: HashMap<&ParseError, usize> = HashMap::new();
+    for error in original {
+        *remaining.entry(error).or_default() += 1;
+    }
+
+    fixed.into_iter().any(|mut error| {
+        if let Some((start, end)) = error.span {
+            match (original_offset(start, replaced), original_offset(end, replaced)) {
+                (Some(start), Some(end)) => error.span = Some((start, end)),
+                _ => return true,
+            }
+        }
+        match remaining.get_mut(&error) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        }
+    })
+}
+
+/// Where a splice replaced text: `start..end` of the original became `len` bytes.
+struct Replaced {
+    start: usize,
+    end: usize,
+    len: usize,
+}
+
+/// Maps an offset in spliced content back to the original, or `None` inside replaced text.
+fn original_offset(offset: usize, replaced: &[Replaced]) -> Option<usize> {
+    let mut shift = 0isize;
+    for r in replaced {
+        let start = r.start.checked_add_signed(shift)?;
+        if offset < start {
             break;
         }
-        if c == '\n' {
-            line += 1;
-            col = 1;
-        } else {
-            col += 1;
+        if offset < start + r.len {
+            return None;
+        }
+        shift += r.len as isize - (r.end - r.start) as isize;
+    }
+    offset.checked_add_signed(-shift)
+}
+
+/// Applies fixes to `content`, back to front so earlier spans stay valid, and returns what was replaced, front to back.
+/// Newlines in replacements follow the file's line ending style, and a leading BOM is kept.
+fn splice_fixes(content: &str, fixes: &[(&'static str, &Fix)]) -> (String, Vec<Replaced>) {
+    let mut fixes = fixes.to_vec();
+    // Sort fixes by start position descending
+    fixes.sort_by_key(|(_, fix)| std::cmp::Reverse(fix.span.start));
+
+    let crlf = content.contains("\r\n");
+    let mut new_content = content.to_string();
+    let mut replaced = Vec::new();
+    for (_, fix) in fixes {
+        let abs_start = fix.span.start as usize;
+        let abs_end = fix.span.end as usize;
+
+        // Check boundaries
+        if abs_start > new_content.len() || abs_end > new_content.len() {
+            continue;
         }
+
+        let len = if crlf {
+            let replacement = fix.replacement.replace("\r\n", "\n").replace('\n', "\r\n");
+            new_content.replace_range(abs_start..abs_end, &replacement);
+            replacement.len()
+        } else {
+            new_content.replace_range(abs_start..abs_end, &fix.replacement);
+            fix.replacement.len()
+        };
+        replaced.push(Replaced { start: abs_start, end: abs_end, len });
+    }
+
+    if content.starts_with('\u{FEFF}') && !new_content.starts_with('\u{FEFF}') {
+        new_content.insert(0, '\u{FEFF}');
+        replaced.push(Replaced { start: 0, end: 0, len: '\u{FEFF}'.len_utf8() });
     }
-    (line, col)
+    replaced.reverse();
+    (new_content, replaced)
 }
 
+/// Writes `content` to a temp file next to `path`, then renames it over `path`.
+/// A crash mid-write leaves the original untouched. Permissions are copied from the original.
+/// Symlinks are resolved first, so the link stays a link and its target gets the new content.
+fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
+    let path = &fs::canonicalize(path)?;
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    let file_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let tmp_path = dir.join(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    let result = (|| {
+        let permissions = fs::metadata(path)?.permissions();
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        fs::set_permissions(&tmp_path, permissions)?;
+        fs::rename(&tmp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
+}
@@ -0,0 +1,355 @@
+use crate::ignores::Ignores;
+use linter_test::directives::disable_next_line_comment;
+use linter_test::line_index::LineIndex;
+use linter_test::linter::extract_sfc;
+use linter_test::{rules, DiagnosticWithLocation, Fix, LintResult, Linter, LinterConfig, Severity};
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
+    DidSaveTextDocument, Notification as _, PublishDiagnostics, ShowMessage,
+};
+use lsp_types::request::{CodeActionRequest, RegisterCapability, Request as _};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionProviderCapability,
+    DiagnosticSeverity, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+    DidChangeWatchedFilesRegistrationOptions, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams, FileSystemWatcher, GlobPattern, InitializeParams, MessageType,
+    NumberOrString, Position, PublishDiagnosticsParams, Range, Registration, RegistrationParams,
+    ServerCapabilities, ShowMessageParams, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, TextDocumentSyncSaveOptions, TextEdit, Url, WorkspaceEdit,
+};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE: &str = ".linterrc.json";
+
+struct Document {
+    text: String,
+    result: Option<LintResult>, // None for documents that are ignored or don't have a lintable extension
+}
+
+struct Server {
+    connection: Connection,
+    root: PathBuf,
+    linter: Linter,
+    ignores: Ignores,
+    documents: HashMap<Url, Document>,
+}
+
+/// Runs the language server over stdio until the client asks it to exit.
+pub fn run() -> Result<(), Box<dyn Error + Sync + Send>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
+            open_close: Some(true),
+            change: Some(TextDocumentSyncKind::INCREMENTAL),
+            save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+            ..Default::default()
+        })),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        ..Default::default()
+    };
+    let init_params = connection.initialize(serde_json::to_value(capabilities)?)?;
+    let init_params: InitializeParams = serde_json::from_value(init_params)?;
+
+    #[allow(deprecated)] // `root_uri` is still what most clients send
+    let root = init_params.workspace_folders.as_ref()
+        .and_then(|folders| folders.first())
+        .map(|folder| folder.uri.clone())
+        .or(init_params.root_uri)
+        .and_then(|uri| uri.to_file_path().ok())
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+
+    let mut server = Server {
+        connection,
+        linter: build_linter(LinterConfig::default()),
+        ignores: Ignores::load(&root, &[], false),
+        root,
+        documents: HashMap::new(),
+    };
+    server.reload_config();
+    server.watch_config()?;
+    server.main_loop()?;
+
+    // The writer thread only exits once every sender is dropped.
+    drop(server);
+    io_threads.join()?;
+    Ok(())
+}
+
+impl Server {
+    fn main_loop(&mut self) -> Result<(), Box<dyn Error + Sync + Send>> {
+        while let Ok(msg) = self.connection.receiver.recv() {
+            match msg {
+                Message::Request(req) => {
+                    if self.connection.handle_shutdown(&req)? {
+                        return Ok(());
+                    }
+                    self.handle_request(req)?;
+                }
+                Message::Notification(not) => self.handle_notification(not)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, req: Request) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let response = match req.method.as_str() {
+            CodeActionRequest::METHOD => {
+                let (id, params) = req.extract::<CodeActionParams>(CodeActionRequest::METHOD)?;
+                Response::new_ok(id, self.code_actions(&params))
+            }
+            _ => Response::new_err(
+                req.id,
+                lsp_server::ErrorCode::MethodNotFound as i32,
+                format!("Unhandled method: {}", req.method),
+            ),
+        };
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    fn handle_notification(&mut self, not: Notification) -> Result<(), Box<dyn Error + Sync + Send>> {
+        match not.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = not.extract(DidOpenTextDocument::METHOD)?;
+                self.update(params.text_document.uri, params.text_document.text)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = not.extract(DidChangeTextDocument::METHOD)?;
+                let uri = params.text_document.uri;
+                let Some(doc) = self.documents.get(&uri) else {
+                    return Ok(());
+                };
+                let mut text = doc.text.clone();
+                for change in params.content_changes {
+                    match change.range {
+                        Some(range) => {
+                            let index = LineIndex::new(&text);
+                            let start = to_offset(&index, range.start);
+                            let end = to_offset(&index, range.end);
+                            text.replace_range(start..end, &change.text);
+                        }
+                        None => text = change.text,
+                    }
+                }
+                self.update(uri, text)?;
+            }
+            DidSaveTextDocument::METHOD => {
+                let params: DidSaveTextDocumentParams = not.extract(DidSaveTextDocument::METHOD)?;
+                if is_config(&params.text_document.uri) {
+                    self.reload_config();
+                    self.relint_all()?;
+                } else if let Some(doc) = self.documents.get(&params.text_document.uri) {
+                    let text = params.text.unwrap_or_else(|| doc.text.clone());
+                    self.update(params.text_document.uri, text)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = not.extract(DidCloseTextDocument::METHOD)?;
+                self.documents.remove(&params.text_document.uri);
+                self.publish(params.text_document.uri, Vec::new())?;
+            }
+            DidChangeWatchedFiles::METHOD => {
+                let params: DidChangeWatchedFilesParams = not.extract(DidChangeWatchedFiles::METHOD)?;
+                if params.changes.iter().any(|change| is_config(&change.uri)) {
+                    self.reload_config();
+                    self.relint_all()?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Rebuilds the linter and ignore rules from the workspace config. On a broken config the old ones are kept.
+    fn reload_config(&mut self) {
+        match LinterConfig::from_file(&self.root.join(CONFIG_FILE)) {
+            Ok(config) => {
+                self.ignores = Ignores::load(&self.root, &config.ignore_patterns, false);
+                self.linter = build_linter(config);
+            }
+            Err(e) => self.show_message(MessageType::ERROR, e),
+        }
+    }
+
+    /// Asks the client to tell us when the config file changes on disk.
+    fn watch_config(&self) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let options = DidChangeWatchedFilesRegistrationOptions {
+            watchers: vec![FileSystemWatcher {
+                glob_pattern: GlobPattern::String(format!("**/{}", CONFIG_FILE)),
+                kind: None,
+            }],
+        };
+        let params = RegistrationParams {
+            registrations: vec![Registration {
+                id: "watch-linterrc".to_string(),
+                method: DidChangeWatchedFiles::METHOD.to_string(),
+                register_options: Some(serde_json::to_value(options)?),
+            }],
+        };
+        let req = Request::new(RequestId::from("register-watch-linterrc".to_string()), RegisterCapability::METHOD.to_string(), params);
+        self.connection.sender.send(Message::Request(req))?;
+        Ok(())
+    }
+
+    /// Re-lints a single document and publishes its diagnostics.
+    /// Documents the CLI would skip are still tracked, so a config change can pick them up.
+    fn update(&mut self, uri: Url, text: String) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let path = uri_to_path(&uri);
+        let lintable = self.linter.is_lintable(&path) && !self.ignores.is_ignored(&path, false);
+        let result = lintable.then(|| self.linter.lint_source(&path, &text, false));
+        let diagnostics = result.iter().flat_map(|r| &r.diagnostics).map(to_lsp_diagnostic).collect();
+        self.documents.insert(uri.clone(), Document { text, result });
+        self.publish(uri, diagnostics)
+    }
+
+    fn relint_all(&mut self) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let open: Vec<(Url, String)> = self.documents.iter()
+            .map(|(uri, doc)| (uri.clone(), doc.text.clone()))
+            .collect();
+        for (uri, text) in open {
+            self.update(uri, text)?;
+        }
+        Ok(())
+    }
+
+    fn publish(&self, uri: Url, diagnostics: Vec<lsp_types::Diagnostic>) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let params = PublishDiagnosticsParams { uri, diagnostics, version: None };
+        let not = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection.sender.send(Message::Notification(not))?;
+        Ok(())
+    }
+
+    fn show_message(&self, typ: MessageType, message: String) {
+        let not = Notification::new(ShowMessage::METHOD.to_string(), ShowMessageParams { typ, message });
+        let _ = self.connection.sender.send(Message::Notification(not));
+    }
+
+    /// Quick fixes, suggestions and "disable for this line" for diagnostics touching the range.
+    fn code_actions(&self, params: &CodeActionParams) -> Vec<CodeActionOrCommand> {
+        let uri = &params.text_document.uri;
+        let Some((doc, result)) = self.documents.get(uri).and_then(|doc| Some((doc, doc.result.as_ref()?))) else {
+            return Vec::new();
+        };
+        let index = LineIndex::new(&doc.text);
+        let sfc = extract_sfc(&uri_to_path(uri), doc.text.clone());
+        let script_ranges: Vec<_> = std::iter::once((sfc.script_start_offset, sfc.script_content.len()))
+            .chain(sfc.other_script.iter().map(|s| (s.start_offset, s.content.len())))
+            .map(|(start, len)| start..start + len)
+            .collect();
+
+        let mut actions = Vec::new();
+        for d in &result.diagnostics {
+            let range = to_range(d);
+            if range.end < params.range.start || range.start > params.range.end {
+                continue;
+            }
+            let lsp_diagnostic = to_lsp_diagnostic(d);
+
+            if let Some(fix) = &d.fix {
+                actions.push(action(
+                    format!("Fix: {}", d.message),
+                    uri,
+                    vec![to_text_edit(&index, fix)],
+                    &lsp_diagnostic,
+                    true,
+                ));
+            }
+            for suggestion in &d.suggestions {
+                actions.push(action(
+                    suggestion.message.clone(),
+                    uri,
+                    suggestion.edits.iter().map(|e| to_text_edit(&index, e)).collect(),
+                    &lsp_diagnostic,
+                    false,
+                ));
+            }
+
+            // Insert the disable comment on its own line above, with the same indentation.
+            let line_start = index.offset(d.start_line, 1);
+            let indent: String = doc.text[line_start..].chars().take_while(|c| *c == ' ' || *c == '\t').collect();
+            let comment = disable_next_line_comment(&d.rule, script_ranges.iter().any(|r| r.contains(&d.start_offset)));
+            let insert_at = Position::new(d.start_line as u32 - 1, 0);
+            actions.push(action(
+                format!("Disable {} for this line", d.rule),
+                uri,
+                vec![TextEdit::new(Range::new(insert_at, insert_at), format!("{}{}\n", indent, comment))],
+                &lsp_diagnostic,
+                false,
+            ));
+        }
+        actions
+    }
+}
+
+fn build_linter(config: LinterConfig) -> Linter {
+    let mut linter = Linter::new(config);
+    for rule in rules::builtin() {
+        linter.add_rule(rule);
+    }
+    linter
+}
+
+fn action(title: String, uri: &Url, edits: Vec<TextEdit>, diagnostic: &lsp_types::Diagnostic, preferred: bool) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..Default::default()
+        }),
+        is_preferred: Some(preferred),
+        ..Default::default()
+    })
+}
+
+fn to_lsp_diagnostic(d: &DiagnosticWithLocation) -> lsp_types::Diagnostic {
+    lsp_types::Diagnostic {
+        range: to_range(d),
+        severity: Some(match d.severity {
+            Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+        }),
+        code: Some(NumberOrString::String(d.rule.clone())),
+        source: Some(env!("CARGO_PKG_NAME").to_string()),
+        message: d.message.clone(),
+        ..Default::default()
+    }
+}
+
+/// Our lines and UTF-16 columns are 1-based; LSP's are 0-based.
+fn to_range(d: &DiagnosticWithLocation) -> Range {
+    Range::new(
+        Position::new(d.start_line as u32 - 1, d.start_column as u32 - 1),
+        Position::new(d.end_line as u32 - 1, d.end_column as u32 - 1),
+    )
+}
+
+fn to_position(index: &LineIndex, offset: usize) -> Position {
+    let lc = index.line_col(offset);
+    Position::new(lc.line as u32 - 1, lc.column as u32 - 1)
+}
+
+fn to_offset(index: &LineIndex, position: Position) -> usize {
+    index.offset(position.line as usize + 1, position.character as usize + 1)
+}
+
+fn to_text_edit(index: &LineIndex, fix: &Fix) -> TextEdit {
+    TextEdit::new(
+        Range::new(to_position(index, fix.span.start as usize), to_position(index, fix.span.end as usize)),
+        fix.replacement.clone(),
+    )
+}
+
+fn uri_to_path(uri: &Url) -> PathBuf {
+    uri.to_file_path().unwrap_or_else(|_| PathBuf::from(uri.path()))
+}
+
+fn is_config(uri: &Url) -> bool {
+    Path::new(uri.path()).file_name().is_some_and(|name| name == CONFIG_FILE)
+}
@@ -1,127 +1,302 @@
-mod linter;
-mod rules;
+mod baseline;
+mod cache;
 mod cli;
-mod template_parser;
+mod git;
+mod ignores;
+mod lsp;
+mod watch;
 
 use clap::Parser;
 use ignore::WalkBuilder;
 use rayon::prelude::*;
-use crate::linter::{Linter, LintResult, LinterConfig};
-use crate::cli::Args;
-use crate::rules::no_console::NoConsole;
-use crate::rules::no_process_env::NoProcessEnv;
-use crate::rules::no_v_html::NoVHtml;
+use linter_test::formatters::{self, OutputFormat};
+use linter_test::{rules, FixFilter, Linter, LintResult, LinterConfig};
+use crate::baseline::Baseline;
+use crate::cache::Cache;
+use crate::cli::{Args, Command};
+use crate::git::ChangedFiles;
+use crate::ignores::Ignores;
 use std::fs;
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
 
 fn main() {
     let args = Args::parse();
+
+    if let Some(Command::Lsp) = args.command {
+        if let Err(e) = lsp::run() {
+            eprintln!("Language server error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
     
     // Load config
-    let config_path = ".linterrc.json";
-    let config = if fs::metadata(config_path).is_ok() {
-        let content = fs::read_to_string(config_path).expect("Failed to read .linterrc.json");
-        serde_json::from_str(&content).expect("Failed to parse .linterrc.json")
-    } else {
-        LinterConfig::default()
-    };
+    let mut config = LinterConfig::from_file(Path::new(".linterrc.json")).unwrap_or_else(|e| panic!("{}", e));
+    if !args.ext.is_empty() {
+        config.extensions = args.ext.clone();
+    }
+    config.ignore_patterns.extend(args.ignore_pattern.iter().cloned());
+
+    let cache = args.cache.then(|| Cache::load(Path::new(&args.cache_location), &config));
 
     let mut linter = Linter::new(config);
+    linter.set_fix_filter(FixFilter {
+        rules: args.fix_rule.clone(),
+        categories: args.fix_type.clone(),
+    });
     
     // Register rules
-    linter.add_rule(Box::new(NoConsole));
-    linter.add_rule(Box::new(NoProcessEnv));
-    linter.add_rule(Box::new(NoVHtml));
-    
-    // New Rules (Phase 5)
-    use crate::rules::vue::require_v_for_key::RequireVForKey;
-    use crate::rules::vue::mustache_interpolation_spacing::MustacheInterpolationSpacing;
-    use crate::rules::nuxt::prefer_import_meta::PreferImportMeta;
-    
-    linter.add_rule(Box::new(RequireVForKey));
-    linter.add_rule(Box::new(MustacheInterpolationSpacing));
-    linter.add_rule(Box::new(PreferImportMeta));
+    for rule in rules::builtin() {
+        linter.add_rule(rule);
+    }
+
+    let format = if args.json { OutputFormat::Json } else { args.format };
+
+    if args.stdin {
+        lint_stdin(&linter, &args, format);
+        return;
+    }
+
+    // Ask git which files changed, if requested
+    let changed = if args.changed_since.is_some() || args.staged {
+        match ChangedFiles::from_git(args.changed_since.as_deref(), args.staged) {
+            Ok(changed) => Some(changed),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(2);
+            }
+        }
+    } else {
+        if args.diff_lines_only {
+            eprintln!("`--diff-lines-only` needs `--changed-since <REF>` or `--staged`");
+            std::process::exit(2);
+        }
+        None
+    };
+
+    let baseline = args.baseline.as_ref().map(|path| {
+        Baseline::load(Path::new(path)).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        })
+    });
+
+    // Machine-readable reports on stdout must not be mixed with banner/summary lines.
+    let show_summary = format.is_human() || args.output_file.is_some();
 
-    if !args.json {
+    if show_summary {
         println!("🚀 Starting Speedy Nuxt Linter...");
         if args.fix {
             println!("🔧 Autofix enabled");
         }
     }
 
-    // 1. Collect all target files recursively using ignore
+    let mut pipeline = Pipeline { cache, changed, baseline, write_baseline: args.write_baseline.clone() };
+
+    // 1. Collect all target files recursively using ignore, narrowed down to files git considers changed
+    let files_to_lint = pipeline.select(collect_files(&args, &linter, show_summary));
+
+    let file_count = files_to_lint.len();
+    if show_summary {
+        println!("📂 Analyzing {} files...", file_count);
+    }
+
+    // 2. Parallel Linting with Rayon
+    let results = pipeline.lint(&linter, &args, &files_to_lint, show_summary);
+
+    write_report(&results, format, &args);
+    if show_summary {
+        print_summary(&results);
+    }
+
+    if args.watch {
+        watch::run(&linter, &args, format, &mut pipeline, results);
+    }
+}
+
+/// Everything around linting the collected files: git narrowing, the cache, `--write-baseline` and the
+/// baseline filter. The first run and every `--watch` re-run go through the same steps.
+struct Pipeline {
+    cache: Option<Cache>,
+    changed: Option<ChangedFiles>,
+    baseline: Option<Baseline>,
+    write_baseline: Option<String>, // Taken by the first run, which sees every file
+}
+
+impl Pipeline {
+    /// Keeps the files git considers changed, when `--changed-since` or `--staged` is given.
+    fn select(&self, mut files: Vec<PathBuf>) -> Vec<PathBuf> {
+        files.retain(|path| self.selects(path));
+        files
+    }
+
+    fn selects(&self, path: &Path) -> bool {
+        self.changed.as_ref().is_none_or(|changed| changed.contains(path))
+    }
+
+    /// Asks git again; edits made while watching change which files and lines count as changed.
+    fn refresh_changed(&mut self, args: &Args) {
+        if self.changed.is_none() {
+            return;
+        }
+        match ChangedFiles::from_git(args.changed_since.as_deref(), args.staged) {
+            Ok(changed) => self.changed = Some(changed),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+
+    fn lint(&mut self, linter: &Linter, args: &Args, files: &[PathBuf], show_summary: bool) -> Vec<LintResult> {
+        let cache = self.cache.as_ref();
+        let mut results: Vec<LintResult> = files.par_iter()
+            .filter_map(|path| {
+                let cached = cache.and_then(|cache| {
+                    let content = fs::read_to_string(path).ok()?;
+                    cache.get(path, &content, args.fix)
+                });
+                cached.or_else(|| linter.lint_file(path, args.fix))
+            })
+            .collect();
+
+        if let Some(cache) = &mut self.cache {
+            for result in &results {
+                cache.insert(result);
+            }
+            cache.save();
+        }
+
+        if let (Some(changed), true) = (&self.changed, args.diff_lines_only) {
+            for result in &mut results {
+                let path = Path::new(&result.path);
+                result.diagnostics.retain(|d| changed.contains_line(path, d.start_line));
+            }
+        }
+
+        if let Some(path) = self.write_baseline.take() {
+            let baseline = Baseline::from_results(&results);
+            match baseline.save(Path::new(&path)) {
+                Ok(()) if show_summary => println!("📝 Recorded {} diagnostic(s) in {}", baseline.len(), path),
+                Ok(()) => {}
+                Err(e) => eprintln!("{}", e),
+            }
+        }
+        if let Some(baseline) = &self.baseline {
+            baseline.filter(&mut results);
+        }
+        results
+    }
+}
+
+/// Walks the input paths (respecting .gitignore and ignore patterns) and returns the files with a lintable extension.
+fn collect_files(args: &Args, linter: &Linter, show_errors: bool) -> Vec<PathBuf> {
+    let ignores = (!args.no_ignore).then(|| Ignores::load(Path::new("."), &linter.config().ignore_patterns, show_errors));
+
     let mut files_to_lint = Vec::new();
     for input_path in &args.files {
+        // Explicitly named paths are never filtered by the walker, so check them here
+        if let Some(ignores) = &ignores {
+            let path = Path::new(input_path);
+            if ignores.is_ignored(path, path.is_dir()) {
+                // Always on stderr: a named file silently missing from the report looks like a clean file
+                eprintln!("⚠️  {} is ignored by an ignore pattern. Use `--no-ignore` to lint it anyway.", input_path);
+                continue;
+            }
+        }
+
         // WalkBuilder respects .gitignore by default
-        let walker = WalkBuilder::new(input_path)
+        let mut walker = WalkBuilder::new(input_path);
+        walker
             .hidden(false) // default: ignore hidden files, but we might want them? let's stick to defaults usually
-            .git_ignore(true)
-            .build();
+            .git_ignore(true);
+        if let Some(ignores) = ignores.clone() {
+            walker.filter_entry(move |entry| !ignores.is_ignored(entry.path(), entry.file_type().is_some_and(|t| t.is_dir())));
+        }
+        let walker = walker.build();
 
         for result in walker {
             match result {
                 Ok(entry) => {
                     let path = entry.path();
-                    if path.is_file() {
-                        if let Some(ext) = path.extension() {
-                            if ext == "vue" {
-                                files_to_lint.push(path.to_path_buf());
-                            }
-                        }
+                    if path.is_file() && linter.is_lintable(path) {
+                        files_to_lint.push(path.to_path_buf());
                     }
                 },
                 Err(err) => {
-                    if !args.json {
+                    if show_errors {
                         eprintln!("Error walking directory: {}", err);
                     }
                 }
             }
         }
     }
+    files_to_lint
+}
 
-    let file_count = files_to_lint.len();
-    if !args.json {
-        println!("📂 Analyzing {} files...", file_count);
+/// Formats the results and prints them, or writes them to `--output-file`.
+fn write_report(results: &[LintResult], format: OutputFormat, args: &Args) {
+    let color = args.output_file.is_none() && std::io::stdout().is_terminal();
+    let report = formatters::get_formatter(format, color).format(results);
+    match &args.output_file {
+        Some(output_file) => {
+            if let Err(e) = fs::write(output_file, &report) {
+                eprintln!("Failed to write report to {}: {}", output_file, e);
+            }
+        }
+        None => print!("{}", report),
     }
+}
 
-    // 2. Parallel Linting with Rayon
-    let results: Vec<LintResult> = files_to_lint.par_iter()
-        .filter_map(|path| linter.lint_file(path, args.fix))
-        .collect();
+fn print_summary<'a>(results: impl IntoIterator<Item = &'a LintResult>) {
+    let mut total_issues = 0;
+    let mut total_fixed = 0;
+
+    for result in results {
+        total_issues += result.diagnostics.len();
+
+        if result.fixed_count > 0 {
+            println!("✨ Fixed {} issue(s) in {}", result.fixed_count, result.path);
+            total_fixed += result.fixed_count;
+        }
+    }
 
-    if args.json {
-        let json_output = serde_json::to_string_pretty(&results).unwrap();
-        println!("{}", json_output);
+    if total_issues == 0 && total_fixed == 0 {
+         println!("✨ No issues found!");
     } else {
-        // Text output
-        let mut total_issues = 0;
-        let mut total_fixed = 0;
+         println!("✨ Done! Found {} issues. Fixed {}.", total_issues, total_fixed);
+    }
+}
 
-        for result in &results {
-            if result.diagnostics.is_empty() && result.fixed_count == 0 {
-                continue;
-            }
+/// Lints stdin for editor integrations. No banner or summary.
+/// With `--fix`, stdout carries the fixed content and the report goes to stderr (or `--output-file`).
+fn lint_stdin(linter: &Linter, args: &Args, format: OutputFormat) {
+    let mut content = String::new();
+    if let Err(e) = std::io::stdin().read_to_string(&mut content) {
+        eprintln!("Failed to read stdin: {}", e);
+        std::process::exit(2);
+    }
 
-            for d in &result.diagnostics {
-                println!("❌ {} ({}:{}) - {} [{}]", 
-                    result.path, 
-                    d.start_line, 
-                    d.start_column, 
-                    d.message, 
-                    if d.fix_available { "🔧" } else { "" }
-                );
-                total_issues += 1;
-            }
+    // The file name decides the parser and whether ignore patterns apply, as if the content were on disk
+    let path = Path::new(&args.stdin_filename);
+    let ignored = !args.no_ignore && Ignores::load(Path::new("."), &linter.config().ignore_patterns, false).is_ignored(path, false);
+    if ignored {
+        eprintln!("⚠️  {} is ignored by an ignore pattern. Use `--no-ignore` to lint it anyway.", args.stdin_filename);
+    }
+    let result = (!ignored).then(|| linter.lint_source(path, &content, args.fix));
 
-            if result.fixed_count > 0 {
-                println!("✨ Fixed {} issue(s) in {}", result.fixed_count, result.path);
-                total_fixed += result.fixed_count;
+    let color = args.output_file.is_none() && !args.fix && std::io::stdout().is_terminal();
+    let report = formatters::get_formatter(format, color).format(result.as_slice());
+    match &args.output_file {
+        Some(output_file) => {
+            if let Err(e) = fs::write(output_file, &report) {
+                eprintln!("Failed to write report to {}: {}", output_file, e);
             }
         }
+        None if args.fix => eprint!("{}", report),
+        None => print!("{}", report),
+    }
 
-        if total_issues == 0 && total_fixed == 0 {
-             println!("✨ No issues found!");
-        } else {
-             println!("✨ Done! Found {} issues. Fixed {}.", total_issues, total_fixed);
-        }
+    if args.fix {
+        // Ignored content is echoed back unchanged, so editors piping through `--fix` don't lose it
+        print!("{}", result.as_ref().and_then(|r| r.fixed_source.as_deref()).unwrap_or(&content));
     }
 }
@@ -0,0 +1,4 @@
+pub mod valid_json;
+pub mod no_missing_keys;
+pub mod no_missing_locale_keys;
+pub mod unsupported_block;
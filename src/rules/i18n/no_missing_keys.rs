@@ -0,0 +1,39 @@
+use crate::i18n::Messages;
+use crate::linter::{Context, Diagnostic, Rule, RuleCategory};
+
+pub struct NoMissingKeys;
+
+impl Rule for NoMissingKeys {
+    fn name(&self) -> &'static str {
+        "i18n/no-missing-keys"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // Without local messages the keys live in global locale files we can't see; the same goes for skipped blocks
+        let messages = ctx.i18n();
+        if messages.locales.is_empty() || messages.incomplete {
+            return diagnostics;
+        }
+
+        for key in &ctx.bindings().translations {
+            for locale in &messages.locales {
+                if !Messages::has_key(locale, &key.name) {
+                    diagnostics.push(Diagnostic {
+                        message: format!("Translation key `{}` is missing in locale `{}`.", key.name, locale.locale),
+                        span: key.span,
+                        fix: None,
+                        suggestions: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,43 @@
+use crate::i18n::Messages;
+use crate::linter::{Context, Diagnostic, Rule, RuleCategory};
+
+pub struct NoMissingLocaleKeys;
+
+impl Rule for NoMissingLocaleKeys {
+    fn name(&self) -> &'static str {
+        "i18n/no-missing-locale-keys"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let messages = ctx.i18n();
+        if messages.incomplete {
+            return diagnostics; // A skipped block may hold the "missing" keys
+        }
+        let locales = &messages.locales;
+
+        for locale in locales {
+            let mut reported: Vec<&str> = Vec::new();
+            for other in locales.iter().filter(|l| l.locale != locale.locale) {
+                for key in &other.keys {
+                    if reported.contains(&key.path.as_str()) || Messages::has_key(locale, &key.path) {
+                        continue;
+                    }
+                    reported.push(&key.path);
+                    diagnostics.push(Diagnostic {
+                        message: format!("Locale `{}` is missing `{}`, which `{}` has.", locale.locale, key.path, other.locale),
+                        span: locale.span,
+                        fix: None,
+                        suggestions: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,29 @@
+use crate::linter::{Context, Diagnostic, Rule, RuleCategory, Severity};
+
+/// `<i18n>` blocks in a language we can't read. Their keys aren't checked, so this only warns.
+pub struct UnsupportedBlock;
+
+impl Rule for UnsupportedBlock {
+    fn name(&self) -> &'static str {
+        "i18n/unsupported-block"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        ctx.i18n().unsupported.iter()
+            .map(|block| Diagnostic {
+                message: block.message.clone(),
+                span: block.span,
+                fix: None,
+                suggestions: Vec::new(),
+            })
+            .collect()
+    }
+}
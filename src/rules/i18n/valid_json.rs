@@ -0,0 +1,24 @@
+use crate::linter::{Context, Diagnostic, Rule, RuleCategory};
+
+pub struct ValidJson;
+
+impl Rule for ValidJson {
+    fn name(&self) -> &'static str {
+        "i18n/valid-json"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        ctx.i18n().errors.iter()
+            .map(|error| Diagnostic {
+                message: error.message.clone(),
+                span: error.span,
+                fix: None,
+                suggestions: Vec::new(),
+            })
+            .collect()
+    }
+}
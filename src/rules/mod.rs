@@ -1,5 +1,32 @@
 pub mod no_console;
+pub mod no_important;
 pub mod no_process_env;
 pub mod no_v_html;
 pub mod vue;
 pub mod nuxt;
+pub mod i18n;
+
+use crate::linter::Rule;
+
+/// All rules that ship with the linter, in registration order.
+pub fn builtin() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(no_console::NoConsole),
+        Box::new(no_process_env::NoProcessEnv),
+        Box::new(no_v_html::NoVHtml),
+        Box::new(vue::require_v_for_key::RequireVForKey),
+        Box::new(vue::mustache_interpolation_spacing::MustacheInterpolationSpacing),
+        Box::new(nuxt::prefer_import_meta::PreferImportMeta),
+        Box::new(no_important::NoImportant),
+        Box::new(vue::no_unused_scoped_class::NoUnusedScopedClass),
+        Box::new(vue::no_undef_style_v_bind::NoUndefStyleVBind),
+        Box::new(vue::no_unused_vars::NoUnusedVars),
+        Box::new(vue::no_use_v_if_with_v_for::NoUseVIfWithVFor),
+        Box::new(vue::valid_v_for::ValidVFor),
+        Box::new(vue::valid_v_if_chain::ValidVIfChain),
+        Box::new(i18n::valid_json::ValidJson),
+        Box::new(i18n::no_missing_keys::NoMissingKeys),
+        Box::new(i18n::no_missing_locale_keys::NoMissingLocaleKeys),
+        Box::new(i18n::unsupported_block::UnsupportedBlock),
+    ]
+}
@@ -1,6 +1,7 @@
 use oxc_ast::ast::*;
-use oxc_span::Span;
-use crate::linter::{Rule, Context, Diagnostic, Fix};
+use oxc_ast::{AstKind, AstType};
+use crate::linter::{Rule, Context, Diagnostic, Fix, RuleCategory};
+use crate::visitor::AstNode;
 
 pub struct NoConsole;
 
@@ -9,33 +10,58 @@ impl Rule for NoConsole {
         "no-console"
     }
 
-    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
-        let mut diagnostics = Vec::new();
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Suggestion
+    }
+
+    fn node_types(&self) -> &'static [AstType] {
+        &[AstType::CallExpression]
+    }
 
-        for stmt in &ctx.program.body {
-            if let Statement::ExpressionStatement(expr_stmt) = stmt {
-                if let Expression::CallExpression(call_expr) = &expr_stmt.expression {
-                     if let Expression::StaticMemberExpression(member) = &call_expr.callee {
-                        if let Expression::Identifier(obj) = &member.object {
-                            if obj.name == "console" {
-                                // "log", "warn", "error" etc.
-                                // For MVP, we catch everything under console.*
-                                
-                                diagnostics.push(Diagnostic {
-                                    message: format!("Unexpected console statement: console.{}", member.property.name),
-                                    span: Span::new(expr_stmt.span.start + ctx.source_file.script_start_offset as u32, expr_stmt.span.end + ctx.source_file.script_start_offset as u32),
-                                    fix: Some(Fix {
-                                        span: Span::new(expr_stmt.span.start + ctx.source_file.script_start_offset as u32, expr_stmt.span.end + ctx.source_file.script_start_offset as u32),
-                                        replacement: format!("/* console.{} */", member.property.name), // Comemnt out as fix
-                                    }),
-                                });
-                            }
-                        }
-                    }
-                }
-            }
+    fn run_on_node(&self, node: &AstNode, ctx: &Context) -> Vec<Diagnostic> {
+        let AstKind::CallExpression(call_expr) = node.kind else {
+            return Vec::new();
+        };
+        let Expression::StaticMemberExpression(member) = &call_expr.callee else {
+            return Vec::new();
+        };
+        // "log", "warn", "error" etc.
+        // For MVP, we catch everything under console.*
+        // A local variable or import named `console` is not the global one.
+        if !matches!(&member.object, Expression::Identifier(obj) if obj.name == "console" && ctx.is_global_reference(obj)) {
+            return Vec::new();
         }
-        
-        diagnostics
+
+        let message = format!("Unexpected console statement: console.{}", member.property.name);
+        let diagnostic = match node.parent {
+            // A whole statement in a statement list can be commented out. Anywhere else (a braceless
+            // `if` body, an arrow's expression body) the statement's absence changes the code, and
+            // the call's value may be used, so no fix.
+            Some(AstKind::ExpressionStatement(expr_stmt)) if in_statement_list(node) => Diagnostic {
+                message,
+                span: ctx.script_span(expr_stmt.span),
+                fix: Some(Fix {
+                    span: ctx.script_span(expr_stmt.span),
+                    replacement: format!("/* console.{} */", member.property.name), // Comemnt out as fix
+                }),
+                suggestions: Vec::new(),
+            },
+            _ => Diagnostic {
+                message,
+                span: ctx.script_span(call_expr.span),
+                fix: None,
+                suggestions: Vec::new(),
+            },
+        };
+        vec![diagnostic]
+    }
+}
+
+/// Whether the statement enclosing the call is an item of a statement list.
+fn in_statement_list(node: &AstNode) -> bool {
+    match node.ancestor(1) {
+        Some(AstKind::Program(_) | AstKind::BlockStatement(_) | AstKind::StaticBlock(_) | AstKind::SwitchCase(_)) => true,
+        Some(AstKind::FunctionBody(_)) => !matches!(node.ancestor(2), Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression),
+        _ => false,
     }
 }
@@ -0,0 +1,48 @@
+use crate::css::CssTokenKind;
+use crate::linter::{Context, Diagnostic, Fix, Rule, RuleCategory, Suggestion};
+use oxc_span::Span;
+
+pub struct NoImportant;
+
+impl Rule for NoImportant {
+    fn name(&self) -> &'static str {
+        "no-important"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Suggestion
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for style in &ctx.source_file.styles {
+            let tokens = style.tokenize();
+            for (i, token) in tokens.iter().enumerate() {
+                if token.kind != CssTokenKind::Delim('!') {
+                    continue;
+                }
+                // `! important` is valid too
+                let Some(keyword) = tokens[i + 1..].iter().find(|t| !matches!(t.kind, CssTokenKind::Whitespace | CssTokenKind::Comment)) else { continue };
+                if keyword.kind != CssTokenKind::Ident || !keyword.text(&style.content).eq_ignore_ascii_case("important") {
+                    continue;
+                }
+
+                // Removing it changes the cascade, so it's only offered as a suggestion
+                let leading_space = tokens[..i].last().filter(|t| t.kind == CssTokenKind::Whitespace);
+                let removal = Span::new(leading_space.map_or(token.span.start, |t| t.span.start), keyword.span.end);
+                diagnostics.push(Diagnostic {
+                    message: "Avoid `!important`; it makes styles hard to override.".to_string(),
+                    span: style.span(Span::new(token.span.start, keyword.span.end)),
+                    fix: None,
+                    suggestions: vec![Suggestion {
+                        message: "Remove `!important`".to_string(),
+                        edits: vec![Fix { span: style.span(removal), replacement: String::new() }],
+                    }],
+                });
+            }
+        }
+
+        diagnostics
+    }
+}
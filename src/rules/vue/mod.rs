@@ -1,2 +1,8 @@
 pub mod require_v_for_key;
 pub mod mustache_interpolation_spacing;
+pub mod no_unused_scoped_class;
+pub mod no_undef_style_v_bind;
+pub mod no_unused_vars;
+pub mod no_use_v_if_with_v_for;
+pub mod valid_v_for;
+pub mod valid_v_if_chain;
@@ -1,4 +1,4 @@
-use crate::linter::{Context, Diagnostic, Rule, Fix};
+use crate::linter::{Context, Diagnostic, Rule, Fix, RuleCategory};
 use crate::template_parser::TemplateToken;
 use oxc_span::Span;
 use regex::Regex;
@@ -11,6 +11,10 @@ impl Rule for MustacheInterpolationSpacing {
         "vue/mustache-interpolation-spacing"
     }
 
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
     fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
         
@@ -52,6 +56,7 @@ impl Rule for MustacheInterpolationSpacing {
                                         span: Span::new(abs_start, abs_end),
                                         replacement: format!("{{{{ {} }}}}", trimmed),
                                     }),
+                                    suggestions: Vec::new(),
                                 });
                              }
                          }
@@ -0,0 +1,28 @@
+use crate::linter::{Context, Diagnostic, Rule, RuleCategory};
+
+pub struct NoUndefStyleVBind;
+
+impl Rule for NoUndefStyleVBind {
+    fn name(&self) -> &'static str {
+        "vue/no-undef-style-v-bind"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        if ctx.source_file.styles.is_empty() {
+            return Vec::new();
+        }
+
+        ctx.bindings().undefined_style_references()
+            .map(|reference| Diagnostic {
+                message: format!("`{}` in `v-bind()` is not defined in `<script setup>`.", reference.name),
+                span: reference.span,
+                fix: None,
+                suggestions: Vec::new(),
+            })
+            .collect()
+    }
+}
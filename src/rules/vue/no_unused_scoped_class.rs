@@ -0,0 +1,184 @@
+use crate::css::{CssToken, CssTokenKind};
+use crate::linter::{Context, Diagnostic, Rule, RuleCategory, StyleBlock};
+use crate::template_ast::{walk_elements, TemplateNode};
+use oxc_span::Span;
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+pub struct NoUnusedScopedClass;
+
+impl Rule for NoUnusedScopedClass {
+    fn name(&self) -> &'static str {
+        "vue/no-unused-scoped-class"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let Some(template) = ctx.template else { return diagnostics };
+        let Some(used) = TemplateClasses::collect(template) else { return diagnostics };
+
+        for style in ctx.source_file.styles.iter().filter(|s| s.scoped) {
+            let tokens = style.tokenize();
+            // `.block { &__item {} }` builds class names we can't see, so prefixes count as used
+            let has_suffix_nesting = tokens.windows(2).any(|pair| {
+                pair[0].kind == CssTokenKind::Delim('&') && pair[1].kind == CssTokenKind::Ident && pair[0].span.end == pair[1].span.start
+            });
+
+            for (name, span) in selector_classes(style, &tokens) {
+                if !used.contains(name, has_suffix_nesting) {
+                    diagnostics.push(Diagnostic {
+                        message: format!("The scoped class `.{}` is not used in the template.", name),
+                        span: style.span(span),
+                        fix: None,
+                        suggestions: Vec::new(),
+                    });
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Classes the template may apply.
+#[derive(Default)]
+struct TemplateClasses {
+    names: HashSet<String>,
+    prefixes: Vec<String>,        // From concatenations like `'btn-' + size`
+    transitions: Vec<String>,     // `<Transition name="...">` (`v` by default)
+    any_transition: bool,         // A `<Transition>` with a dynamic name
+}
+
+impl TemplateClasses {
+    /// `None` when a class binding can't be read (e.g. `:class="classes"` from the script).
+    fn collect(template: &[TemplateNode]) -> Option<Self> {
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let words = RE.get_or_init(|| Regex::new(r"[A-Za-z_-][\w-]*").unwrap());
+
+        let mut classes = TemplateClasses::default();
+        let mut readable = true;
+        walk_elements(template, &mut |element| {
+            let tag = element.name.replace('-', "").to_ascii_lowercase();
+            if tag == "transition" || tag == "transitiongroup" {
+                match (element.attribute("name"), element.directive("bind").filter(|a| a.directive().and_then(|d| d.argument) == Some("name"))) {
+                    (_, Some(_)) => classes.any_transition = true,
+                    (Some(name), None) => classes.transitions.extend(name.value.clone()),
+                    (None, None) => classes.transitions.push("v".to_string()),
+                }
+            }
+
+            for attr in &element.attributes {
+                let Some(value) = &attr.value else { continue };
+                let argument = attr.directive().and_then(|d| (d.name == "bind").then_some(d.argument).flatten());
+                match argument {
+                    // `class="a b"`, and class props like `enter-active-class` / `active-class`
+                    None if attr.directive().is_none() && (attr.name.eq_ignore_ascii_case("class") || attr.name.ends_with("-class")) => {
+                        classes.names.extend(value.split_whitespace().map(str::to_string));
+                    }
+                    // `:class="{ active: isActive }"`, `:class="['a', b]"`: every word may be a class
+                    Some(argument) if argument == "class" || argument.ends_with("-class") || argument.ends_with("Class") => {
+                        if !value.contains(['\'', '"', '`', '{', '[']) {
+                            readable = false;
+                        }
+                        for word in words.find_iter(value) {
+                            let word = word.as_str();
+                            if word.ends_with('-') || word.ends_with('_') {
+                                classes.prefixes.push(word.to_string());
+                            }
+                            classes.names.insert(word.to_string());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        readable.then_some(classes)
+    }
+
+    fn contains(&self, name: &str, match_prefixes: bool) -> bool {
+        self.names.contains(name)
+            || self.prefixes.iter().any(|prefix| name.starts_with(prefix.as_str()))
+            || (match_prefixes && self.names.iter().any(|used| {
+                used.strip_prefix(name).is_some_and(|rest| rest.starts_with(['-', '_']))
+            }))
+            || self.is_transition_class(name)
+    }
+
+    fn is_transition_class(&self, name: &str) -> bool {
+        const SUFFIXES: &[&str] = &["-enter-from", "-enter-active", "-enter-to", "-leave-from", "-leave-active", "-leave-to", "-move", "-enter", "-leave"];
+        SUFFIXES.iter().any(|suffix| name.strip_suffix(suffix).is_some_and(|prefix| {
+            self.any_transition || self.transitions.iter().any(|t| t == prefix)
+        }))
+    }
+}
+
+/// Class selectors (`.foo`) in rule preludes, with their spans in the block.
+/// Skips at-rule preludes, and selectors that target other components or are negated
+/// (`:deep()`, `:global()`, `:slotted()`, `:not()`, `>>>`, `/deep/`, `::v-deep`).
+fn selector_classes<'s>(style: &'s StyleBlock, tokens: &[CssToken]) -> Vec<(&'s str, Span)> {
+    let mut classes = Vec::new();
+    let mut segment_start = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token.kind {
+            CssTokenKind::Semicolon | CssTokenKind::CloseBrace => segment_start = i + 1,
+            CssTokenKind::OpenBrace => {
+                let prelude = &tokens[segment_start..i];
+                segment_start = i + 1;
+                let first = prelude.iter().find(|t| !matches!(t.kind, CssTokenKind::Whitespace | CssTokenKind::Comment));
+                if first.is_some_and(|t| t.kind == CssTokenKind::AtKeyword) {
+                    continue;
+                }
+                collect_prelude_classes(&style.content, prelude, &mut classes);
+            }
+            _ => {}
+        }
+    }
+
+    classes
+}
+
+fn collect_prelude_classes<'s>(source: &'s str, prelude: &[CssToken], classes: &mut Vec<(&'s str, Span)>) {
+    const OPAQUE: &[&str] = &["deep(", "global(", "slotted(", "not(", "v-deep(", "v-global(", "v-slotted("];
+
+    let mut parens: Vec<bool> = Vec::new(); // Whether each open paren is opaque
+    let mut deep_until_comma = false;
+
+    for (i, token) in prelude.iter().enumerate() {
+        let text = token.text(source);
+        let next = prelude.get(i + 1);
+        let adjacent = |t: &CssToken| t.span.start == token.span.end;
+        match token.kind {
+            CssTokenKind::Function => {
+                let after_colon = i > 0 && prelude[i - 1].kind == CssTokenKind::Colon;
+                parens.push(after_colon && OPAQUE.contains(&text));
+            }
+            CssTokenKind::OpenParen => parens.push(false),
+            CssTokenKind::CloseParen => {
+                parens.pop();
+            }
+            CssTokenKind::Comma if parens.is_empty() => deep_until_comma = false,
+            CssTokenKind::Delim('>') if text == ">" && next.is_some_and(|t| t.kind == CssTokenKind::Delim('>') && adjacent(t)) => deep_until_comma = true,
+            CssTokenKind::Ident if text == "deep" && i > 0 && prelude[i - 1].kind == CssTokenKind::Delim('/') => deep_until_comma = true,
+            CssTokenKind::Ident if text == "v-deep" && i > 0 && prelude[i - 1].kind == CssTokenKind::Colon => deep_until_comma = true,
+            CssTokenKind::Delim('.') if !deep_until_comma && !parens.contains(&true) => {
+                let Some(name) = next.filter(|t| t.kind == CssTokenKind::Ident && adjacent(t)) else { continue };
+                // `.btn-#{$size}` / `.btn-@{size}` are built at compile time
+                let interpolated = prelude.get(i + 2).is_some_and(|t| {
+                    t.span.start == name.span.end && matches!(t.kind, CssTokenKind::Interpolation | CssTokenKind::Delim('@' | '#' | '$'))
+                });
+                if !interpolated {
+                    classes.push((name.text(source), Span::new(token.span.start, name.span.end)));
+                }
+            }
+            _ => {}
+        }
+    }
+}
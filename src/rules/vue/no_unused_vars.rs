@@ -0,0 +1,59 @@
+use crate::bindings::{TemplateLocal, TemplateLocalKind};
+use crate::linter::{Context, Diagnostic, Fix, Rule, RuleCategory};
+use oxc_span::Span;
+
+pub struct NoUnusedVars;
+
+impl Rule for NoUnusedVars {
+    fn name(&self) -> &'static str {
+        "vue/no-unused-vars"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        if ctx.template.is_none() {
+            return Vec::new();
+        }
+
+        ctx.bindings().template_locals.iter()
+            .filter(|local| !local.used && !local.name.starts_with('_'))
+            .map(|local| {
+                let what = match local.kind {
+                    TemplateLocalKind::VForAlias => "`v-for` alias",
+                    TemplateLocalKind::SlotProp => "slot prop",
+                };
+                Diagnostic {
+                    message: format!("The {} '{}' is defined but never used.", what, local.name),
+                    span: local.span,
+                    fix: trailing_index_removal(&ctx.source_file.original_content, local),
+                    suggestions: Vec::new(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Removes the last parameter of a `v-for` alias list: `(item, index)` becomes `(item)`.
+/// Other positions can't go without shifting the meaning of the rest.
+fn trailing_index_removal(source: &str, local: &TemplateLocal) -> Option<Fix> {
+    if local.kind != TemplateLocalKind::VForAlias {
+        return None;
+    }
+    let (start, end) = (local.span.start as usize, local.span.end as usize);
+
+    // A plain identifier right before the closing paren, not part of a pattern or default value
+    let after = source[end..].trim_start();
+    if !after.starts_with(')') {
+        return None;
+    }
+    let before = source[..start].trim_end();
+    let before = before.strip_suffix(',')?.trim_end();
+    if before.ends_with('(') {
+        return None;
+    }
+
+    Some(Fix { span: Span::new(before.len() as u32, end as u32), replacement: String::new() })
+}
@@ -0,0 +1,85 @@
+use crate::bindings::{analyze_template_code, split_v_for, TemplateCode};
+use crate::linter::{Context, Diagnostic, Fix, Rule, RuleCategory, Suggestion};
+use crate::template_ast::{walk_elements, Attribute, Element};
+use oxc_span::Span;
+
+pub struct NoUseVIfWithVFor;
+
+impl Rule for NoUseVIfWithVFor {
+    fn name(&self) -> &'static str {
+        "vue/no-use-v-if-with-v-for"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let Some(template) = ctx.template else { return diagnostics };
+        let source = &ctx.source_file.template_content;
+
+        walk_elements(template, &mut |element| {
+            let (Some(v_if), Some(v_for)) = (element.directive("if"), element.directive("for")) else { return };
+
+            // In Vue 3 `v-if` is evaluated first, so it can't see the `v-for` aliases
+            let v_for_value = v_for.value.as_deref().and_then(split_v_for);
+            let aliases: Vec<String> = v_for_value
+                .and_then(|v| analyze_template_code(v.alias, TemplateCode::Params))
+                .map(|info| info.declared.into_iter().map(|(name, _)| name).collect())
+                .unwrap_or_default();
+            let uses_alias = v_if.value.as_deref()
+                .and_then(|condition| analyze_template_code(condition, TemplateCode::Expression))
+                .is_some_and(|info| info.references.iter().any(|(name, _)| aliases.contains(name)));
+
+            let (message, suggestion) = if uses_alias {
+                let list = v_for_value.map_or("the list", |v| v.source.trim());
+                (
+                    format!("Don't use `v-if` with `v-for` on the same element; filter `{}` in a computed property instead, or wrap the element in `<template v-for>`.", list),
+                    wrap(ctx, source, element, "Wrap in `<template v-for>`", &[Some(v_for), key_attribute(element)].into_iter().flatten().collect::<Vec<_>>()),
+                )
+            } else {
+                (
+                    "Don't use `v-if` with `v-for` on the same element; move the `v-if` to a wrapper `<template>`.".to_string(),
+                    wrap(ctx, source, element, "Move `v-if` to a wrapper `<template>`", &[v_if]),
+                )
+            };
+
+            diagnostics.push(Diagnostic {
+                message,
+                span: ctx.template_span(v_if.span),
+                fix: None,
+                suggestions: vec![suggestion],
+            });
+        });
+
+        diagnostics
+    }
+}
+
+/// `:key` / `v-bind:key`, which moves with `v-for` onto a `<template>` in Vue 3.
+fn key_attribute(element: &Element) -> Option<&Attribute> {
+    element.attributes.iter().find(|a| a.directive().is_some_and(|d| d.name == "bind" && d.argument == Some("key")))
+}
+
+/// Wraps `element` in a `<template>` carrying `moved` (taken off the element).
+fn wrap(ctx: &Context, source: &str, element: &Element, message: &str, moved: &[&Attribute]) -> Suggestion {
+    let outer = element.outer_span();
+    let attributes: Vec<&str> = moved.iter().map(|attr| &source[attr.span.start as usize..attr.span.end as usize]).collect();
+
+    let mut edits = vec![Fix {
+        span: ctx.template_span(Span::new(outer.start, outer.start)),
+        replacement: format!("<template {}>", attributes.join(" ")),
+    }];
+    for attr in moved {
+        // Take the whitespace before the attribute with it
+        let start = source[..attr.span.start as usize].trim_end().len() as u32;
+        edits.push(Fix { span: ctx.template_span(Span::new(start, attr.span.end)), replacement: String::new() });
+    }
+    edits.push(Fix {
+        span: ctx.template_span(Span::new(outer.end, outer.end)),
+        replacement: "</template>".to_string(),
+    });
+
+    Suggestion { message: message.to_string(), edits }
+}
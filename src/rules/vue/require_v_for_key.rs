@@ -1,6 +1,5 @@
-use crate::linter::{Context, Diagnostic, Rule};
-use crate::template_parser::TemplateToken;
-use oxc_span::Span;
+use crate::linter::{Context, Diagnostic, Rule, RuleCategory};
+use crate::template_ast::{walk_elements, Element};
 
 pub struct RequireVForKey;
 
@@ -9,33 +8,44 @@ impl Rule for RequireVForKey {
         "vue/require-v-for-key"
     }
 
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
     fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
+        let Some(template) = ctx.template else { return diagnostics };
+
+        walk_elements(template, &mut |element| {
+            if element.directive("for").is_none() || has_key(element) {
+                return;
+            }
+            // A `<template v-for>` renders its children; Vue 2 code keys them instead of the template.
+            // Children with their own `v-for` are checked on their own.
+            let unkeyed: Vec<&Element> = if element.is_template() {
+                element.elements().filter(|child| child.directive("for").is_none() && !has_key(child)).collect()
+            } else {
+                vec![element]
+            };
 
-        if let Some(tokens) = ctx.template_tokens {
-            for token in tokens {
-                if let TemplateToken::StartTag { attributes, span, .. } = token {
-                     // Check if v-for exists
-                     if attributes.contains_key("v-for") {
-                         // Check if :key or v-bind:key exists
-                         let has_key = attributes.contains_key(":key") || attributes.contains_key("v-bind:key");
-                         
-                         if !has_key {
-                             // Report error
-                             let abs_start = span.start + ctx.source_file.template_start_offset as u32;
-                             let abs_end = span.end + ctx.source_file.template_start_offset as u32;
-                             
-                             diagnostics.push(Diagnostic {
-                                message: "Elements in iteration expect to have 'v-bind:key' directives.".to_string(),
-                                span: Span::new(abs_start, abs_end),
-                                fix: None, // Too complex to autofix (need to choose key)
-                            });
-                         }
-                     }
-                }
+            for element in unkeyed {
+                diagnostics.push(Diagnostic {
+                    message: "Elements in iteration expect to have 'v-bind:key' directives.".to_string(),
+                    span: ctx.template_span(element.span),
+                    fix: None, // Too complex to autofix (need to choose key)
+                    suggestions: Vec::new(),
+                });
             }
-        }
+        });
 
         diagnostics
     }
 }
+
+/// A static `key` counts too; vue/valid-v-for reports it, so it isn't reported twice.
+fn has_key(element: &Element) -> bool {
+    element.attributes.iter().any(|a| match a.directive() {
+        Some(d) => d.name == "bind" && d.argument == Some("key"),
+        None => a.name == "key",
+    })
+}
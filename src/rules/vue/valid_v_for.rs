@@ -0,0 +1,153 @@
+use crate::bindings::{analyze_template_code, split_v_for, TemplateCode};
+use crate::linter::{Context, Diagnostic, Rule, RuleCategory};
+use crate::template_ast::{walk_elements, Attribute, Element};
+
+pub struct ValidVFor;
+
+impl Rule for ValidVFor {
+    fn name(&self) -> &'static str {
+        "vue/valid-v-for"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let Some(template) = ctx.template else { return diagnostics };
+
+        walk_elements(template, &mut |element| {
+            let Some(v_for) = element.directive("for") else { return };
+            let report = |message: String, attr: &Attribute| Diagnostic {
+                message,
+                span: ctx.template_span(attr.span),
+                fix: None,
+                suggestions: Vec::new(),
+            };
+
+            let aliases = match parse_aliases(v_for) {
+                Ok(aliases) => aliases,
+                Err(message) => {
+                    diagnostics.push(report(message, v_for));
+                    return;
+                }
+            };
+
+            // Vue 3 puts the key on `<template v-for>` itself; Vue 2 code keys the children instead
+            let keys: Vec<&Attribute> = match key_attribute(element) {
+                Some(key) => vec![key],
+                None if element.is_template() => element.elements()
+                    .filter(|child| child.directive("for").is_none())
+                    .filter_map(key_attribute)
+                    .collect(),
+                None => Vec::new(),
+            };
+
+            for key in keys {
+                if let Some(message) = check_key(key, &aliases) {
+                    diagnostics.push(report(message, key));
+                }
+            }
+        });
+
+        diagnostics
+    }
+}
+
+/// The names a `v-for` declares, with their position in `(value, key, index)`.
+/// A destructured value declares several names at position 0.
+struct Aliases {
+    names: Vec<(String, usize)>,
+    value: String, // The value alias as written, for messages
+}
+
+fn parse_aliases(v_for: &Attribute) -> Result<Aliases, String> {
+    if v_for.directive().is_some_and(|d| d.argument.is_some()) {
+        return Err("`v-for` directives don't take an argument.".to_string());
+    }
+    let value = v_for.value.as_deref().filter(|v| !v.trim().is_empty())
+        .ok_or_else(|| "`v-for` directives require a value.".to_string())?;
+    let parsed = split_v_for(value)
+        .ok_or_else(|| format!("Invalid `v-for` expression `{}`; expected `item in list`.", value.trim()))?;
+
+    let alias = parsed.alias.trim();
+    let params = analyze_template_code(alias, TemplateCode::Params)
+        .ok_or_else(|| format!("Invalid `v-for` alias `{}`.", alias))?;
+    let top_level = alias_list(alias);
+    if top_level.len() > 3 {
+        return Err(format!("Too many `v-for` aliases in `{}`; expected at most `(value, key, index)`.", alias));
+    }
+    if top_level.iter().any(|a| a.is_empty()) {
+        return Err(format!("Invalid `v-for` alias `{}`.", alias));
+    }
+    if analyze_template_code(parsed.source, TemplateCode::Expression).is_none() {
+        return Err(format!("Invalid `v-for` source `{}`.", parsed.source.trim()));
+    }
+
+    let names = params.declared.into_iter()
+        .map(|(name, _)| {
+            let position = top_level.iter().skip(1).position(|a| *a == name).map_or(0, |i| i + 1);
+            (name, position)
+        })
+        .collect();
+    Ok(Aliases { names, value: top_level[0].to_string() })
+}
+
+/// Splits `(a, { b, c }, i)` into its top-level parts; a bare alias is a single part.
+fn alias_list(alias: &str) -> Vec<&str> {
+    let Some(inner) = alias.strip_prefix('(').and_then(|a| a.strip_suffix(')')) else { return vec![alias] };
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(inner[start..].trim());
+    parts
+}
+
+fn key_attribute(element: &Element) -> Option<&Attribute> {
+    element.attributes.iter().find(|a| match a.directive() {
+        Some(d) => d.name == "bind" && d.argument == Some("key"),
+        None => a.name == "key",
+    })
+}
+
+/// Whether a key identifies items by their value: it must use an alias other than a position index.
+fn check_key(key: &Attribute, aliases: &Aliases) -> Option<String> {
+    let value_alias = &aliases.value;
+    if key.directive().is_none() {
+        return Some(format!("The static `key` gives every item the same identity; bind a unique value from `{}`.", value_alias));
+    }
+    let references = analyze_template_code(key.value.as_deref()?, TemplateCode::Expression)?.references;
+    let used: Vec<&(String, usize)> = aliases.names.iter().filter(|(alias, _)| references.iter().any(|(name, _)| name == alias)).collect();
+
+    if references.is_empty() {
+        return Some(format!("The `:key` is constant, so every item gets the same identity; use a unique value from `{}`.", value_alias));
+    }
+    if used.is_empty() {
+        return Some(format!("The `:key` doesn't use the `v-for` alias; use a unique value from `{}`.", value_alias));
+    }
+    if used.iter().all(|(alias, position)| is_index(alias, *position)) {
+        return Some(format!("Using the index `{}` as `:key` breaks when the list is reordered; use a unique value from `{}`.", used[0].0, value_alias));
+    }
+    None
+}
+
+/// The third alias is always the index. The second is the index of an array, or the key of an object;
+/// only index-like names (`i`, `idx`, `index`, `rowIndex`) are treated as an index there.
+fn is_index(alias: &str, position: usize) -> bool {
+    match position {
+        2 => true,
+        1 => matches!(alias, "i" | "idx" | "index") || alias.ends_with("Index") || alias.ends_with("_index"),
+        _ => false,
+    }
+}
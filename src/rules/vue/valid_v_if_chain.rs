@@ -0,0 +1,83 @@
+use crate::linter::{Context, Diagnostic, Fix, Rule, RuleCategory};
+use crate::template_ast::{Attribute, TemplateNode};
+
+pub struct ValidVIfChain;
+
+impl Rule for ValidVIfChain {
+    fn name(&self) -> &'static str {
+        "vue/valid-v-if-chain"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        if let Some(template) = ctx.template {
+            check_siblings(ctx, template, &mut diagnostics);
+        }
+        diagnostics
+    }
+}
+
+const CONDITIONALS: &[&str] = &["if", "else-if", "else"];
+
+fn check_siblings(ctx: &Context, nodes: &[TemplateNode], diagnostics: &mut Vec<Diagnostic>) {
+    let mut report = |message: String, attr: &Attribute, fix: Option<Fix>| diagnostics.push(Diagnostic {
+        message,
+        span: ctx.template_span(attr.span),
+        fix,
+        suggestions: Vec::new(),
+    });
+
+    // Whether the previous sibling (ignoring whitespace and comments) can be continued by `v-else(-if)`
+    let mut chain_open = false;
+    let mut nested = Vec::new();
+
+    for node in nodes {
+        let element = match node {
+            TemplateNode::Text(text) if text.content.trim().is_empty() => continue,
+            TemplateNode::Comment(_) => continue,
+            TemplateNode::Text(_) => {
+                chain_open = false;
+                continue;
+            }
+            TemplateNode::Element(element) => element,
+        };
+        nested.push(element);
+
+        let conditionals: Vec<(&str, &Attribute)> = element.attributes.iter()
+            .filter_map(|attr| attr.directive().map(|d| (d.name, attr)))
+            .filter(|(name, _)| CONDITIONALS.contains(name))
+            .collect();
+
+        for (_, extra) in conditionals.iter().skip(1) {
+            report("An element can't have more than one of `v-if`, `v-else-if` and `v-else`.".to_string(), extra, None);
+        }
+
+        match conditionals.first() {
+            Some(&(name, attr)) => {
+                let has_value = attr.value.as_deref().is_some_and(|v| !v.trim().is_empty());
+                if name == "else" && attr.value.is_some() {
+                    report(
+                        "`v-else` doesn't take a value.".to_string(),
+                        attr,
+                        Some(Fix { span: ctx.template_span(attr.span), replacement: attr.name.clone() }),
+                    );
+                } else if name != "else" && !has_value {
+                    report(format!("`v-{}` requires a condition.", name), attr, None);
+                }
+                if name != "if" && !chain_open {
+                    report(format!("`v-{}` must directly follow an element with `v-if` or `v-else-if`.", name), attr, None);
+                }
+                chain_open = name != "else";
+            }
+            None => chain_open = false,
+        }
+    }
+
+    for element in nested {
+        check_siblings(ctx, &element.children, diagnostics);
+    }
+}
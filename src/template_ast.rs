@@ -0,0 +1,292 @@
+use crate::template_parser::TemplateToken;
+use oxc_span::Span;
+
+/// A node of the template tree. Spans are relative to the template content, like token spans.
+#[derive(Debug, Clone)]
+pub enum TemplateNode {
+    Element(Element),
+    Text(Text),
+    Comment(Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct Element {
+    pub name: String, // As written (the tokenizer lowercases tag names)
+    pub attributes: Vec<Attribute>,
+    pub self_closing: bool,
+    pub span: Span, // The start tag, `<` to `>`
+    pub end_tag: Option<Span>, // `</name>`; `None` for self-closing, void and implicitly closed elements
+    pub children: Vec<TemplateNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    pub name: String, // As written, e.g. `:fooBar` or `v-for`
+    pub value: Option<String>, // Raw source text between the quotes; `None` for bare attributes
+    pub span: Span,
+    pub value_span: Option<Span>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Text {
+    pub content: String, // Raw source text (entities are not decoded)
+    pub span: Span,
+}
+
+/// A directive attribute split into its parts: `v-bind:foo.prop` is `bind` with argument `foo`.
+/// Shorthands are expanded: `:foo` is `bind`, `@click` is `on` and `#item` is `slot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Directive<'a> {
+    pub name: &'a str,
+    pub argument: Option<&'a str>,
+}
+
+impl Attribute {
+    pub fn directive(&self) -> Option<Directive<'_>> {
+        let (name, rest) = if let Some(rest) = self.name.strip_prefix(':').or_else(|| self.name.strip_prefix('.')) {
+            ("bind", Some(rest))
+        } else if let Some(rest) = self.name.strip_prefix('@') {
+            ("on", Some(rest))
+        } else if let Some(rest) = self.name.strip_prefix('#') {
+            ("slot", Some(rest))
+        } else if let Some(rest) = self.name.strip_prefix("v-") {
+            match rest.split_once(':') {
+                Some((name, argument)) => (name, Some(argument)),
+                None => (rest.split('.').next().unwrap_or(rest), None),
+            }
+        } else {
+            return None;
+        };
+
+        // Drop modifiers, but keep dots inside a dynamic `[argument]`
+        let argument = rest.map(|arg| match arg.strip_prefix('[').and_then(|a| a.find(']')) {
+            Some(end) => &arg[..end + 2],
+            None => arg.split('.').next().unwrap_or(arg),
+        });
+        Some(Directive { name, argument })
+    }
+}
+
+impl Element {
+    /// Plain attribute by name (case-insensitive, like HTML).
+    pub fn attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.name.eq_ignore_ascii_case(name))
+    }
+
+    /// First directive with the given name (`"if"`, `"for"`, `"bind"`, ...).
+    pub fn directive(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.directive().is_some_and(|d| d.name == name))
+    }
+
+    pub fn is_template(&self) -> bool {
+        self.name.eq_ignore_ascii_case("template")
+    }
+
+    /// From the start of the start tag to the end of the end tag (or of the last child if there is none).
+    pub fn outer_span(&self) -> Span {
+        let end = match (self.end_tag, self.children.last()) {
+            (Some(end_tag), _) => end_tag.end,
+            (None, Some(child)) => child.outer_span().end,
+            (None, None) => self.span.end,
+        };
+        Span::new(self.span.start, end)
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            TemplateNode::Element(element) => Some(element),
+            _ => None,
+        })
+    }
+}
+
+impl TemplateNode {
+    pub fn outer_span(&self) -> Span {
+        match self {
+            TemplateNode::Element(element) => element.outer_span(),
+            TemplateNode::Text(text) => text.span,
+            TemplateNode::Comment(span) => *span,
+        }
+    }
+}
+
+/// Calls `f` for every element in the tree, parents before children.
+pub fn walk_elements<'a>(nodes: &'a [TemplateNode], f: &mut impl FnMut(&'a Element)) {
+    for node in nodes {
+        if let TemplateNode::Element(element) = node {
+            f(element);
+            walk_elements(&element.children, f);
+        }
+    }
+}
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr",
+];
+
+/// Builds the element tree from the token stream.
+///
+/// Token spans are unreliable once the tokenizer rewrites input (lowercased names, decoded entities),
+/// so each tag is located in `template_content` by walking a cursor forward, and attributes are
+/// re-read from the source to get exact spans and their original case.
+pub fn build(template_content: &str, tokens: &[TemplateToken]) -> Vec<TemplateNode> {
+    let mut builder = TreeBuilder { source: template_content, cursor: 0, stack: vec![Vec::new()], open: Vec::new() };
+
+    for token in tokens {
+        match token {
+            TemplateToken::StartTag { name, self_closing, .. } => {
+                let Some(start) = builder.find_tag(&format!("<{}", name)) else { continue };
+                builder.push_text(start);
+                let (element_name, attributes, end) = scan_start_tag(template_content, start);
+                builder.cursor = end;
+
+                let element = Element {
+                    name: element_name,
+                    attributes,
+                    self_closing: *self_closing,
+                    span: Span::new(start as u32, end as u32),
+                    end_tag: None,
+                    children: Vec::new(),
+                };
+                if *self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                    builder.current().push(TemplateNode::Element(element));
+                } else {
+                    builder.open.push(element);
+                    builder.stack.push(Vec::new());
+                }
+            }
+            TemplateToken::EndTag { name, .. } => {
+                let Some(start) = builder.find_tag(&format!("</{}", name)) else { continue };
+                builder.push_text(start);
+                builder.cursor = template_content[start..].find('>').map_or(template_content.len(), |i| start + i + 1);
+
+                // Close up to the matching element; stray end tags are ignored
+                if let Some(depth) = builder.open.iter().rposition(|e| e.name.eq_ignore_ascii_case(name)) {
+                    builder.open[depth].end_tag = Some(Span::new(start as u32, builder.cursor as u32));
+                    while builder.open.len() > depth {
+                        builder.close();
+                    }
+                }
+            }
+            TemplateToken::Comment { .. } => {
+                let Some(start) = template_content[builder.cursor..].find("<!--").map(|i| builder.cursor + i) else { continue };
+                builder.push_text(start);
+                let end = template_content[start + 4..].find("-->").map_or(template_content.len(), |i| start + 4 + i + 3);
+                builder.current().push(TemplateNode::Comment(Span::new(start as u32, end as u32)));
+                builder.cursor = end;
+            }
+            // Text is whatever lies between tags; see `push_text`
+            TemplateToken::String { .. } | TemplateToken::Eof => {}
+        }
+    }
+
+    builder.push_text(template_content.len());
+    while !builder.open.is_empty() {
+        builder.close(); // Unclosed elements end with the template
+    }
+    builder.stack.pop().unwrap_or_default()
+}
+
+struct TreeBuilder<'s> {
+    source: &'s str,
+    cursor: usize,
+    stack: Vec<Vec<TemplateNode>>, // Children collected so far, one level per open element (plus the root)
+    open: Vec<Element>,
+}
+
+impl TreeBuilder<'_> {
+    fn current(&mut self) -> &mut Vec<TemplateNode> {
+        self.stack.last_mut().unwrap()
+    }
+
+    fn close(&mut self) {
+        let mut element = self.open.pop().unwrap();
+        element.children = self.stack.pop().unwrap();
+        self.current().push(TemplateNode::Element(element));
+    }
+
+    /// Case-insensitive search for `<name` / `</name` followed by a tag delimiter.
+    fn find_tag(&self, prefix: &str) -> Option<usize> {
+        let haystack = &self.source.as_bytes()[self.cursor..];
+        (0..haystack.len()).find(|&i| {
+            haystack[i..].len() > prefix.len()
+                && haystack[i..i + prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+                && matches!(haystack[i + prefix.len()], b' ' | b'\t' | b'\n' | b'\r' | b'\x0C' | b'/' | b'>')
+        }).map(|i| self.cursor + i)
+    }
+
+    fn push_text(&mut self, end: usize) {
+        if end > self.cursor {
+            let text = Text { content: self.source[self.cursor..end].to_string(), span: Span::new(self.cursor as u32, end as u32) };
+            self.current().push(TemplateNode::Text(text));
+        }
+        self.cursor = self.cursor.max(end);
+    }
+}
+
+/// Reads a start tag at `start` (which points at `<`). Returns the name, the attributes and the end offset (after `>`).
+fn scan_start_tag(source: &str, start: usize) -> (String, Vec<Attribute>, usize) {
+    let bytes = source.as_bytes();
+    let is_space = |b: u8| matches!(b, b' ' | b'\t' | b'\n' | b'\r' | b'\x0C');
+
+    let mut i = start + 1;
+    while i < bytes.len() && !is_space(bytes[i]) && bytes[i] != b'/' && bytes[i] != b'>' {
+        i += 1;
+    }
+    let name = source[start + 1..i].to_string();
+
+    let mut attributes = Vec::new();
+    loop {
+        while i < bytes.len() && (is_space(bytes[i]) || bytes[i] == b'/') {
+            i += 1;
+        }
+        if i >= bytes.len() {
+            return (name, attributes, bytes.len());
+        }
+        if bytes[i] == b'>' {
+            return (name, attributes, i + 1);
+        }
+
+        let name_start = i;
+        // `=` can't start a name, but can be its first character (HTML quirk)
+        i += 1;
+        while i < bytes.len() && !is_space(bytes[i]) && !matches!(bytes[i], b'/' | b'>' | b'=') {
+            i += 1;
+        }
+        let attr_name = source[name_start..i].to_string();
+
+        let mut j = i;
+        while j < bytes.len() && is_space(bytes[j]) {
+            j += 1;
+        }
+        if j >= bytes.len() || bytes[j] != b'=' {
+            attributes.push(Attribute { name: attr_name, value: None, span: Span::new(name_start as u32, i as u32), value_span: None });
+            continue;
+        }
+        j += 1;
+        while j < bytes.len() && is_space(bytes[j]) {
+            j += 1;
+        }
+
+        let (value_start, value_end, end) = match bytes.get(j) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let value_end = source[j + 1..].find(quote as char).map_or(bytes.len(), |k| j + 1 + k);
+                (j + 1, value_end, (value_end + 1).min(bytes.len()))
+            }
+            _ => {
+                let mut k = j;
+                while k < bytes.len() && !is_space(bytes[k]) && bytes[k] != b'>' {
+                    k += 1;
+                }
+                (j, k, k)
+            }
+        };
+        attributes.push(Attribute {
+            name: attr_name,
+            value: Some(source[value_start..value_end].to_string()),
+            span: Span::new(name_start as u32, end as u32),
+            value_span: Some(Span::new(value_start as u32, value_end as u32)),
+        });
+        i = end;
+    }
+}
@@ -1,4 +1,4 @@
-use html5gum::{Emitter, Error, State};
+use html5gum::{Emitter, Error, State, Tokenizer};
 use oxc_span::Span;
 use std::collections::BTreeMap;
 use std::str;
@@ -27,6 +27,25 @@ pub enum TemplateToken {
     Eof,
 }
 
+/// Tokenizes template content. Returns the tokens and the number of tokenizer errors.
+pub fn tokenize(template_content: &str) -> (Vec<TemplateToken>, usize) {
+    let (tokens, errors) = tokenize_with_errors(template_content);
+    (tokens, errors.len())
+}
+
+/// Like [`tokenize`], but returns the tokenizer errors themselves. html5gum doesn't say where they are.
+pub fn tokenize_with_errors(template_content: &str) -> (Vec<TemplateToken>, Vec<Error>) {
+    let (emitter, _) = SpannedEmitter::new(template_content);
+    let errors = emitter.errors();
+    let tokenizer = Tokenizer::new_with_emitter(template_content, emitter);
+    // Our SpannedEmitter produces TemplateToken with spans relative to *template_content*.
+    // Rules add `template_start_offset` themselves.
+    // Collect tokens from the iterator (this drains the emitter via pop_token)
+    let tokens: Vec<TemplateToken> = tokenizer.filter_map(|res| res.ok()).collect();
+    let errors = errors.take();
+    (tokens, errors)
+}
+
 pub struct SpannedEmitter {
     base_ptr: usize,
     base_len: usize,
@@ -45,8 +64,7 @@ pub struct SpannedEmitter {
     self_closing: bool,
     
     emitted_tokens: Rc<RefCell<VecDeque<TemplateToken>>>,
-    #[allow(dead_code)]
-    errors: Vec<Error>,
+    errors: Rc<RefCell<Vec<Error>>>,
 }
 
 use std::collections::VecDeque;
@@ -68,7 +86,7 @@ impl SpannedEmitter {
             current_attr_value: String::new(),
             self_closing: false,
             emitted_tokens: tokens.clone(),
-            errors: Vec::new(),
+            errors: Rc::new(RefCell::new(Vec::new())),
         };
         (emitter, tokens)
     }
@@ -81,6 +99,12 @@ impl SpannedEmitter {
         tokens.drain(..).collect()
     }
 
+    /// Shared handle to the tokenizer errors.
+    /// The emitter is moved into the `Tokenizer`, so grab this before tokenizing.
+    pub fn errors(&self) -> Rc<RefCell<Vec<Error>>> {
+        self.errors.clone()
+    }
+
     fn update_span(&mut self, s: &[u8]) {
         let ptr = s.as_ptr() as usize;
         // Simple bounds check to ensure we are looking at original input slice
@@ -141,7 +165,7 @@ impl Emitter for SpannedEmitter {
     }
 
     fn emit_error(&mut self, error: Error) {
-        self.errors.push(error);
+        self.errors.borrow_mut().push(error);
     }
 
     fn pop_token(&mut self) -> Option<Self::Token> {
@@ -0,0 +1,60 @@
+use crate::linter::{Context, Diagnostic, Rule};
+use oxc_ast::{AstKind, AstType};
+use oxc_ast_visit::Visit;
+use std::collections::HashMap;
+
+/// A script AST node handed to `Rule::run_on_node`, with its direct parent.
+pub struct AstNode<'n, 'a> {
+    pub kind: AstKind<'a>,
+    pub parent: Option<AstKind<'a>>,
+    /// All enclosing nodes, outermost first (the last one is `parent`).
+    pub ancestors: &'n [AstKind<'a>],
+}
+
+impl<'a> AstNode<'_, 'a> {
+    /// The `n`th enclosing node: 0 is the parent, 1 the grandparent, and so on.
+    pub fn ancestor(&self, n: usize) -> Option<AstKind<'a>> {
+        self.ancestors.iter().rev().nth(n).copied()
+    }
+}
+
+/// Walks the script AST once and hands each node to the rules that asked for its type
+/// (see `Rule::node_types`). Diagnostics come back tagged with the rule that produced them.
+pub(crate) fn run_node_rules<'r>(rules: &[&'r dyn Rule], ctx: &Context) -> Vec<(&'static str, Diagnostic)> {
+    let mut by_type: HashMap<AstType, Vec<&'r dyn Rule>> = HashMap::new();
+    for rule in rules {
+        for ty in rule.node_types() {
+            by_type.entry(*ty).or_default().push(*rule);
+        }
+    }
+    if by_type.is_empty() {
+        return Vec::new();
+    }
+
+    let mut dispatcher = Dispatcher { by_type, ctx, ancestors: Vec::new(), diagnostics: Vec::new() };
+    dispatcher.visit_program(ctx.program);
+    dispatcher.diagnostics
+}
+
+struct Dispatcher<'a, 'c, 'r> {
+    by_type: HashMap<AstType, Vec<&'r dyn Rule>>,
+    ctx: &'c Context<'a>,
+    ancestors: Vec<AstKind<'a>>,
+    diagnostics: Vec<(&'static str, Diagnostic)>,
+}
+
+impl<'a> Visit<'a> for Dispatcher<'a, '_, '_> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if let Some(rules) = self.by_type.get(&kind.ty()) {
+            let node = AstNode { kind, parent: self.ancestors.last().copied(), ancestors: &self.ancestors };
+            for rule in rules {
+                self.diagnostics.extend(rule.run_on_node(&node, self.ctx).into_iter().map(|d| (rule.name(), d)));
+            }
+        }
+        self.ancestors.push(kind);
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        self.ancestors.pop();
+    }
+}
@@ -0,0 +1,120 @@
+use crate::cli::Args;
+use crate::ignores::{GitIgnores, Ignores};
+use crate::Pipeline;
+use linter_test::formatters::OutputFormat;
+use linter_test::{LintResult, Linter};
+use notify_debouncer_mini::new_debouncer;
+use notify_debouncer_mini::notify::RecursiveMode;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Watches the input paths and re-lints only the files that changed.
+/// Changed paths get the same checks as the walk (extension, .gitignore and ignore patterns), so ignored files never trigger a run.
+/// Re-runs go through the same `pipeline` as the first run (git narrowing, cache and baseline).
+pub fn run(linter: &Linter, args: &Args, format: OutputFormat, pipeline: &mut Pipeline, initial: Vec<LintResult>) {
+    // Latest result per file, keyed by canonical path (notify reports absolute paths).
+    let mut latest: BTreeMap<PathBuf, LintResult> = initial.into_iter()
+        .map(|r| (canonical(Path::new(&r.path)), r))
+        .collect();
+    let ignores = (!args.no_ignore).then(|| Ignores::load(Path::new("."), &linter.config().ignore_patterns, false));
+    let mut git_ignores = GitIgnores::default();
+    let cwd = canonical(Path::new("."));
+    // Machine formats on stdout get nothing but the report
+    let show_progress = format.is_human() || args.output_file.is_some();
+
+    let (tx, rx) = mpsc::channel();
+    let mut debouncer = match new_debouncer(Duration::from_millis(200), tx) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("Failed to start watcher: {}", e);
+            return;
+        }
+    };
+    for input_path in &args.files {
+        if let Err(e) = debouncer.watcher().watch(&canonical(Path::new(input_path)), RecursiveMode::Recursive) {
+            eprintln!("Failed to watch {}: {}", input_path, e);
+        }
+    }
+    if show_progress {
+        println!("👀 Watching for changes... (Ctrl+C to stop)");
+    }
+
+    for events in rx {
+        let events = match events {
+            Ok(events) => events,
+            Err(e) => {
+                eprintln!("Watch error: {}", e);
+                continue;
+            }
+        };
+
+        pipeline.refresh_changed(args);
+
+        let mut changed: Vec<PathBuf> = events.into_iter().map(|e| canonical(&e.path)).collect();
+        changed.sort();
+        changed.dedup();
+
+        let mut to_lint = Vec::new();
+        let mut removed = 0;
+        for path in changed {
+            let lintable = path.is_file()
+                && linter.is_lintable(&path)
+                && !ignores.as_ref().is_some_and(|ignores| ignores.is_ignored(&path, false))
+                && !git_ignores.is_ignored(&path, false)
+                && pipeline.selects(&path);
+            if !lintable {
+                // Deleted, renamed away, now ignored or no longer changed
+                removed += latest.remove(&path).is_some() as usize;
+                continue;
+            }
+            // Reading a file also produces events, so skip files whose content didn't change.
+            if is_unchanged(&path, latest.get(&path)) {
+                continue;
+            }
+            // Keep the path the first run reported; new files are shown relative to the working directory
+            let display = match latest.get(&path) {
+                Some(last) => PathBuf::from(&last.path),
+                None => path.strip_prefix(&cwd).map_or_else(|_| path.clone(), Path::to_path_buf),
+            };
+            to_lint.push(display);
+        }
+        if to_lint.is_empty() && removed == 0 {
+            continue;
+        }
+
+        let results = pipeline.lint(linter, args, &to_lint, false);
+
+        if show_progress {
+            // Clear the screen and move the cursor home
+            print!("\x1B[2J\x1B[1;1H");
+            println!("🔁 Re-linted {} file(s)", results.len());
+        }
+
+        for result in results {
+            latest.insert(canonical(Path::new(&result.path)), result);
+        }
+        // The report covers every watched file, not just the ones re-linted
+        let (paths, results): (Vec<PathBuf>, Vec<LintResult>) = std::mem::take(&mut latest).into_iter().unzip();
+        crate::write_report(&results, format, args);
+        latest = paths.into_iter().zip(results).collect();
+
+        if show_progress {
+            crate::print_summary(latest.values());
+            println!("👀 Watching for changes... (Ctrl+C to stop)");
+        }
+    }
+}
+
+fn is_unchanged(path: &Path, last: Option<&LintResult>) -> bool {
+    match (last, fs::read_to_string(path)) {
+        (Some(last), Ok(content)) => last.fixed_count == 0 && last.source == content,
+        _ => false,
+    }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
@@ -0,0 +1,52 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A project whose baseline records one `console.log(1)` in `comp/a.vue`.
+fn project(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("linter-test-baseline-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("comp")).unwrap();
+    std::fs::write(dir.join("comp/a.vue"), "<script setup>\nconsole.log(1)\n</script>\n").unwrap();
+    let written = lint(&dir, &["--write-baseline"]);
+    assert_eq!(written, vec![2], "--write-baseline still reports everything");
+    assert!(dir.join(".linter-baseline.json").is_file());
+    dir
+}
+
+/// The 1-based start lines of the reported diagnostics.
+fn lint(dir: &Path, args: &[&str]) -> Vec<u64> {
+    let output = Command::new(env!("CARGO_BIN_EXE_linter-test"))
+        .current_dir(dir)
+        .args(["--format", "json", "comp"])
+        .args(args)
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|e| panic!("{}: {}", e, String::from_utf8_lossy(&output.stderr)));
+    report["results"].as_array().unwrap().iter()
+        .flat_map(|r| r["diagnostics"].as_array().unwrap().clone())
+        .map(|d| d["range"]["start"]["line"].as_u64().unwrap())
+        .collect()
+}
+
+#[test]
+fn hides_recorded_diagnostics() {
+    let dir = project("round-trip");
+    assert_eq!(lint(&dir, &["--baseline"]), Vec::<u64>::new());
+    assert_eq!(lint(&dir, &[]), vec![2]);
+}
+
+#[test]
+fn reports_copies_beyond_the_recorded_count() {
+    let dir = project("count");
+    std::fs::write(dir.join("comp/a.vue"), "<script setup>\nconsole.log(1)\nconsole.log(1)\n</script>\n").unwrap();
+    assert_eq!(lint(&dir, &["--baseline"]).len(), 1);
+}
+
+#[test]
+fn survives_reindenting_and_moved_lines() {
+    let dir = project("moved");
+    std::fs::write(dir.join("comp/a.vue"), "<script setup>\nconst a = 1\n\nif (a) {\n  console.log(\n    1\n  )\n}\n</script>\n").unwrap();
+    assert_eq!(lint(&dir, &["--baseline"]), Vec::<u64>::new());
+    assert_eq!(lint(&dir, &[]), vec![5]);
+}
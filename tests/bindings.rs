@@ -0,0 +1,204 @@
+use linter_test::bindings::TemplateLocalKind;
+use linter_test::rules::vue::no_undef_style_v_bind::NoUndefStyleVBind;
+use linter_test::{Context, Diagnostic, Linter, LinterConfig, Rule, RuleCategory};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// What a test looks at, copied out of the `Bindings` of one lint run.
+#[derive(Debug, Default)]
+struct Snapshot {
+    script_setup: bool,
+    setup: Vec<String>,
+    props: Vec<String>,
+    emits: Vec<String>,
+    unused_props: Vec<String>,
+    undefined: Vec<String>,
+    emitted: Vec<String>,
+    locals: Vec<(String, TemplateLocalKind, bool)>,
+    used_in_template: Vec<String>,
+}
+
+/// Copies the bindings out of the lint run; the test keeps the other handle.
+struct Probe(Arc<Mutex<Snapshot>>);
+
+impl Rule for Probe {
+    fn name(&self) -> &'static str {
+        "probe"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Problem
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        let b = ctx.bindings();
+        let names = |iter: &mut dyn Iterator<Item = &String>| iter.cloned().collect::<Vec<_>>();
+        *self.0.lock().unwrap() = Snapshot {
+            script_setup: b.script_setup,
+            setup: names(&mut b.setup.iter().map(|s| &s.name)),
+            props: names(&mut b.props.iter().map(|p| &p.name)),
+            emits: names(&mut b.emits.iter().map(|e| &e.name)),
+            unused_props: names(&mut b.unused_props().map(|p| &p.name)),
+            undefined: names(&mut b.undefined_template_references().map(|r| &r.name)),
+            emitted: names(&mut b.emitted.iter().map(|r| &r.name)),
+            locals: b.template_locals.iter().map(|l| (l.name.clone(), l.kind, l.used)).collect(),
+            used_in_template: b.setup.iter().filter(|s| b.is_used_in_template(&s.name)).map(|s| s.name.clone()).collect(),
+        };
+        Vec::new()
+    }
+}
+
+fn analyze(source: &str) -> Snapshot {
+    let snapshot = Arc::new(Mutex::new(Snapshot::default()));
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(Probe(snapshot.clone())));
+    linter.lint_source(Path::new("Component.vue"), source, false);
+    std::mem::take(&mut *snapshot.lock().unwrap())
+}
+
+#[test]
+fn props_from_interface_with_defaults() {
+    let snapshot = analyze(r#"<template><p>{{ title }}</p></template>
+<script setup lang="ts">
+import type { Item } from './types'
+export interface Props { title: string; count?: number; items: Item[] }
+const props = withDefaults(defineProps<Props>(), { count: 0 })
+const double = props.count * 2
+</script>"#);
+    assert!(snapshot.script_setup);
+    assert_eq!(snapshot.props, ["title", "count", "items"]);
+    assert_eq!(snapshot.unused_props, ["items"]);
+    assert_eq!(snapshot.setup, ["props", "double"]); // Type-only imports and interfaces aren't values
+}
+
+#[test]
+fn props_from_runtime_declaration_and_destructuring() {
+    let snapshot = analyze(r#"<template><p>{{ b }}</p></template>
+<script setup>
+const { a } = defineProps({ a: String, b: Number, c: Boolean })
+</script>"#);
+    assert_eq!(snapshot.props, ["a", "b", "c"]);
+    assert_eq!(snapshot.unused_props, ["c"]);
+}
+
+#[test]
+fn emits_from_call_signatures() {
+    let snapshot = analyze(r#"<template><button @click="$emit('close')">x</button></template>
+<script setup lang="ts">
+const emit = defineEmits<{
+  (e: 'change' | 'update', value: number): void
+  (e: 'close'): void
+}>()
+function save() { emit('change', 1) }
+</script>"#);
+    assert_eq!(snapshot.emits, ["change", "update", "close"]);
+    let mut emitted = snapshot.emitted;
+    emitted.sort();
+    assert_eq!(emitted, ["change", "close"]);
+}
+
+#[test]
+fn emits_from_type_literal_properties() {
+    let snapshot = analyze(r#"<script setup lang="ts">
+const emit = defineEmits<{ change: [id: number]; close: [] }>()
+</script>"#);
+    assert_eq!(snapshot.emits, ["change", "close"]);
+}
+
+#[test]
+fn v_for_aliases_are_scoped_to_the_element_and_its_children() {
+    let snapshot = analyze(r#"<template>
+  <ul>
+    <li v-for="(item, index) in items" :key="item.id"><span>{{ item.name }}</span></li>
+    <li>{{ item }}</li>
+  </ul>
+</template>
+<script setup>
+const items = []
+</script>"#);
+    assert_eq!(snapshot.locals, [
+        ("item".to_string(), TemplateLocalKind::VForAlias, true),
+        ("index".to_string(), TemplateLocalKind::VForAlias, false),
+    ]);
+    assert_eq!(snapshot.undefined, ["item"]); // The sibling is outside the loop
+}
+
+#[test]
+fn slot_props_are_scoped_to_the_children() {
+    let snapshot = analyze(r#"<template>
+  <List :rows="row" v-slot="{ row, unused }"><Cell :value="row" /></List>
+</template>
+<script setup>
+import List from './List.vue'
+import Cell from './Cell.vue'
+</script>"#);
+    assert_eq!(snapshot.locals, [
+        ("row".to_string(), TemplateLocalKind::SlotProp, true),
+        ("unused".to_string(), TemplateLocalKind::SlotProp, false),
+    ]);
+    assert_eq!(snapshot.undefined, ["row"]); // `:rows="row"` on the slot owner can't see the slot props
+    assert_eq!(snapshot.used_in_template, ["List", "Cell"]);
+}
+
+#[test]
+fn template_globals_and_custom_directives() {
+    let snapshot = analyze(r#"<template>
+  <input v-focus :value="Math.max(count, 0)" @input="onInput($event)">
+  {{ missing }}
+</template>
+<script setup>
+const vFocus = { mounted: (el) => el.focus() }
+const count = 1
+const onInput = () => {}
+const unused = 2
+</script>"#);
+    assert_eq!(snapshot.undefined, ["missing"]);
+    assert_eq!(snapshot.used_in_template, ["vFocus", "count", "onInput"]);
+}
+
+#[test]
+fn setup_script_next_to_a_plain_script() {
+    let snapshot = analyze(r#"<script lang="ts">
+export default { inheritAttrs: false }
+</script>
+
+<script setup lang="ts">
+const props = defineProps<{ label: string }>()
+const emit = defineEmits(['close'])
+const open = ref(false)
+</script>
+
+<template><button @click="open = !open">{{ label }} {{ nope }}</button></template>"#);
+    assert!(snapshot.script_setup);
+    assert_eq!(snapshot.setup, ["props", "emit", "open"]);
+    assert_eq!(snapshot.props, ["label"]);
+    assert_eq!(snapshot.emits, ["close"]);
+    assert_eq!(snapshot.undefined, ["nope"]);
+}
+
+#[test]
+fn nothing_is_undefined_without_script_setup() {
+    let snapshot = analyze(r#"<template>{{ message }}</template>
+<script>
+export default { data: () => ({ message: 'hi' }) }
+</script>"#);
+    assert!(!snapshot.script_setup);
+    assert!(snapshot.undefined.is_empty());
+}
+
+#[test]
+fn style_v_bind_sees_the_setup_script_next_to_a_plain_script() {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(NoUndefStyleVBind));
+    let result = linter.lint_source(Path::new("Component.vue"), r#"<script>
+export default { name: 'Badge' }
+</script>
+<script setup>
+const color = 'red'
+</script>
+<style scoped>
+.badge { color: v-bind(color); background: v-bind(missing) }
+</style>"#, false);
+    let messages: Vec<&str> = result.diagnostics.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(messages, ["`missing` in `v-bind()` is not defined in `<script setup>`."]);
+}
@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const SOURCE: &str = "<script setup>\nconsole.log(1)\n</script>\n";
+
+fn project(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("linter-test-cache-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("comp")).unwrap();
+    std::fs::write(dir.join("comp/a.vue"), SOURCE).unwrap();
+    dir
+}
+
+/// Runs with `--cache` and returns the reported messages.
+fn lint(dir: &Path, args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_linter-test"))
+        .current_dir(dir)
+        .args(["--cache", "--format", "json", "comp"])
+        .args(args)
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|e| panic!("{}: {}", e, String::from_utf8_lossy(&output.stderr)));
+    report["results"].as_array().unwrap().iter()
+        .flat_map(|r| r["diagnostics"].as_array().unwrap().clone())
+        .map(|d| d["message"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn cache(dir: &Path) -> String {
+    std::fs::read_to_string(dir.join(".linter-cache")).unwrap()
+}
+
+/// Rewrites the cached messages, so a replayed result can be told apart from a fresh one.
+fn mark_cache(dir: &Path) {
+    let marked = cache(dir).replace("Unexpected console statement", "Cached console statement");
+    std::fs::write(dir.join(".linter-cache"), marked).unwrap();
+}
+
+#[test]
+fn replays_unchanged_files() {
+    let dir = project("hit");
+    assert_eq!(lint(&dir, &[]), vec!["Unexpected console statement: console.log"]);
+    mark_cache(&dir);
+    assert_eq!(lint(&dir, &[]), vec!["Cached console statement: console.log"]);
+}
+
+#[test]
+fn relints_files_whose_content_changed() {
+    let dir = project("content");
+    lint(&dir, &[]);
+    mark_cache(&dir);
+    std::fs::write(dir.join("comp/a.vue"), SOURCE.replace("1", "2")).unwrap();
+    assert_eq!(lint(&dir, &[]), vec!["Unexpected console statement: console.log"]);
+}
+
+#[test]
+fn drops_the_cache_when_the_config_changes() {
+    let dir = project("config");
+    lint(&dir, &[]);
+    mark_cache(&dir);
+    std::fs::write(dir.join(".linterrc.json"), r#"{ "rules": { "no-console": "warn" } }"#).unwrap();
+    assert_eq!(lint(&dir, &[]), vec!["Unexpected console statement: console.log"]);
+}
+
+#[test]
+fn ignores_the_cache_under_fix() {
+    let dir = project("fix");
+    lint(&dir, &[]);
+    mark_cache(&dir);
+    // A replayed result has no fixes to apply, so the file would stay as it is
+    assert_eq!(lint(&dir, &["--fix"]), vec!["Unexpected console statement: console.log"]);
+    assert_eq!(std::fs::read_to_string(dir.join("comp/a.vue")).unwrap(), "<script setup>\n/* console.log */\n</script>\n");
+}
+
+#[test]
+fn prunes_deleted_files() {
+    let dir = project("prune");
+    std::fs::write(dir.join("comp/b.vue"), SOURCE).unwrap();
+    lint(&dir, &[]);
+    assert!(cache(&dir).contains("b.vue"));
+    std::fs::remove_file(dir.join("comp/b.vue")).unwrap();
+    lint(&dir, &[]);
+    assert!(!cache(&dir).contains("b.vue"), "{}", cache(&dir));
+    assert!(cache(&dir).contains("a.vue"));
+}
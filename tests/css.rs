@@ -0,0 +1,77 @@
+use linter_test::css::{tokenize, CssTokenKind};
+use linter_test::rules::no_important::NoImportant;
+use linter_test::rules::vue::no_undef_style_v_bind::NoUndefStyleVBind;
+use linter_test::rules::vue::no_unused_scoped_class::NoUnusedScopedClass;
+use linter_test::{Linter, LinterConfig, Rule};
+use std::path::Path;
+
+fn messages(rule: impl Rule + 'static, source: &str) -> Vec<String> {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(rule));
+    linter.lint_source(Path::new("Test.vue"), source, false).diagnostics.into_iter().map(|d| d.message).collect()
+}
+
+fn unused_classes(template: &str, style: &str) -> Vec<String> {
+    messages(NoUnusedScopedClass, &format!("<template>\n{}\n</template>\n<style scoped>\n{}\n</style>\n", template, style))
+}
+
+#[test]
+fn tokenizes_strings_and_comments_as_single_tokens() {
+    let source = "a { content: \"!important\" } /* !important */";
+    let kinds: Vec<(CssTokenKind, &str)> = tokenize(source, false).iter()
+        .filter(|t| t.kind != CssTokenKind::Whitespace)
+        .map(|t| (t.kind, t.text(source)))
+        .collect();
+    assert_eq!(kinds, vec![
+        (CssTokenKind::Ident, "a"),
+        (CssTokenKind::OpenBrace, "{"),
+        (CssTokenKind::Ident, "content"),
+        (CssTokenKind::Colon, ":"),
+        (CssTokenKind::String, "\"!important\""),
+        (CssTokenKind::CloseBrace, "}"),
+        (CssTokenKind::Comment, "/* !important */"),
+    ]);
+}
+
+#[test]
+fn checks_every_selector_in_a_list() {
+    assert_eq!(
+        unused_classes("<p class=\"active\" />", ".unused, .active:hover { color: red }"),
+        vec!["The scoped class `.unused` is not used in the template."],
+    );
+}
+
+#[test]
+fn sees_classes_in_class_objects_and_arrays() {
+    let style = ".active { color: red }\n.big { font-size: 2em }\n.gone { color: blue }";
+    assert_eq!(
+        unused_classes("<p :class=\"{ active: isActive }\" />\n<p :class=\"['big', size]\" />", style),
+        vec!["The scoped class `.gone` is not used in the template."],
+    );
+}
+
+#[test]
+fn checks_selectors_nested_in_media_queries() {
+    assert_eq!(
+        unused_classes("<p class=\"shown\" />", "@media (max-width: 600px) {\n  .shown { color: red }\n  .hidden { color: blue }\n}"),
+        vec!["The scoped class `.hidden` is not used in the template."],
+    );
+}
+
+#[test]
+fn reports_undefined_style_v_bind_references() {
+    let source = "<script setup>\nconst theme = { color: 'red' }\n</script>\n<style>\na { color: v-bind('theme.color'); background: v-bind(missing); border-color: v-bind('other.color') }\n</style>\n";
+    assert_eq!(messages(NoUndefStyleVBind, source), vec![
+        "`missing` in `v-bind()` is not defined in `<script setup>`.",
+        "`other` in `v-bind()` is not defined in `<script setup>`.",
+    ]);
+}
+
+#[test]
+fn reports_important_outside_comments_and_strings() {
+    let source = "<style>\na { color: red !important; content: \"!important\" }\n/* color: blue !important; */\nb { color: blue ! important }\n</style>\n";
+    assert_eq!(messages(NoImportant, source), vec![
+        "Avoid `!important`; it makes styles hard to override.",
+        "Avoid `!important`; it makes styles hard to override.",
+    ]);
+}
@@ -0,0 +1,45 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The same console call in a component, two plain scripts and a few files the linter shouldn't read.
+fn project(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("linter-test-extensions-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("src/App.vue"), "<script setup>\nconsole.log(1)\n</script>\n").unwrap();
+    for file in ["src/util.ts", "src/plugin.js", "src/notes.md", "src/data.json", "src/style.css"] {
+        std::fs::write(dir.join(file), "console.log(1)\n").unwrap();
+    }
+    dir
+}
+
+/// The file names in the report.
+fn lint(dir: &Path, args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_linter-test")).current_dir(dir).args(["--format", "json"]).args(args).arg("src").output().unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|e| panic!("{}: {}", e, String::from_utf8_lossy(&output.stderr)));
+    let mut names: Vec<String> = report["results"].as_array().unwrap().iter()
+        .map(|r| Path::new(r["path"].as_str().unwrap()).file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn picks_up_plain_scripts_by_default() {
+    assert_eq!(lint(&project("default"), &[]), vec!["App.vue", "plugin.js", "util.ts"]);
+}
+
+#[test]
+fn config_extensions_replace_the_defaults() {
+    let dir = project("config");
+    std::fs::write(dir.join(".linterrc.json"), r#"{ "extensions": ["vue", "ts"] }"#).unwrap();
+    assert_eq!(lint(&dir, &[]), vec!["App.vue", "util.ts"]);
+}
+
+#[test]
+fn ext_overrides_the_config() {
+    let dir = project("flag");
+    std::fs::write(dir.join(".linterrc.json"), r#"{ "extensions": ["vue", "ts"] }"#).unwrap();
+    assert_eq!(lint(&dir, &["--ext", ".js"]), vec!["plugin.js"]);
+}
@@ -0,0 +1,41 @@
+use std::process::Command;
+
+/// A console call (a `suggestion` fix) and an unspaced interpolation (a `style` fix).
+const SOURCE: &str = "<template><p>{{msg}}</p></template>\n<script setup>\nconsole.log(1)\n</script>\n";
+
+/// Runs `--fix` with `args` on a fresh copy of `SOURCE` and returns the file afterwards.
+fn fix(name: &str, args: &[&str]) -> String {
+    let dir = std::env::temp_dir().join(format!("linter-test-fix-filter-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("App.vue");
+    std::fs::write(&file, SOURCE).unwrap();
+    Command::new(env!("CARGO_BIN_EXE_linter-test"))
+        .current_dir(&dir)
+        .args(["--fix", "--format", "json", "App.vue"])
+        .args(args)
+        .output()
+        .unwrap();
+    std::fs::read_to_string(&file).unwrap()
+}
+
+#[test]
+fn applies_every_fix_without_a_filter() {
+    assert_eq!(fix("all", &[]), "<template><p>{{ msg }}</p></template>\n<script setup>\n/* console.log */\n</script>\n");
+}
+
+#[test]
+fn fix_rule_leaves_other_rules_alone() {
+    assert_eq!(
+        fix("rule", &["--fix-rule", "vue/mustache-interpolation-spacing"]),
+        "<template><p>{{ msg }}</p></template>\n<script setup>\nconsole.log(1)\n</script>\n",
+    );
+}
+
+#[test]
+fn fix_type_leaves_other_categories_alone() {
+    assert_eq!(
+        fix("type", &["--fix-type", "suggestion"]),
+        "<template><p>{{msg}}</p></template>\n<script setup>\n/* console.log */\n</script>\n",
+    );
+}
@@ -0,0 +1,78 @@
+use linter_test::{Context, Diagnostic, Fix, LintResult, Linter, LinterConfig, Rule, RuleCategory};
+use oxc_span::Span;
+use std::path::Path;
+
+/// Replaces every `from` in the file with `to`.
+struct Replace {
+    name: &'static str,
+    from: &'static str,
+    to: &'static str,
+}
+
+impl Rule for Replace {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Suggestion
+    }
+
+    fn run(&self, ctx: &Context) -> Vec<Diagnostic> {
+        ctx.source_file.original_content.match_indices(self.from)
+            .map(|(start, _)| {
+                let span = Span::new(start as u32, (start + self.from.len()) as u32);
+                Diagnostic {
+                    message: format!("Replace `{}`.", self.from),
+                    span,
+                    fix: Some(Fix { span, replacement: self.to.to_string() }),
+                    suggestions: Vec::new(),
+                }
+            })
+            .collect()
+    }
+}
+
+fn lint_fix(source: &str, rules: Vec<Replace>) -> LintResult {
+    let mut linter = Linter::new(LinterConfig::default());
+    for rule in rules {
+        linter.add_rule(Box::new(rule));
+    }
+    linter.lint_source(Path::new("test.js"), source, true)
+}
+
+#[test]
+fn refuses_a_fix_that_breaks_parsing_and_names_the_rule() {
+    let result = lint_fix("const a = 1\nconst b = 2\n", vec![
+        Replace { name: "test/unbalanced", from: "2", to: "(2" },
+        Replace { name: "test/rename", from: "const a", to: "const c" },
+    ]);
+    assert_eq!(result.fixed_source, None);
+    assert_eq!(result.fixed_count, 0);
+    assert_eq!(result.fix_error.as_deref(), Some("fixes from test/unbalanced introduce parse errors"));
+}
+
+#[test]
+fn refuses_a_fix_that_trades_one_parse_error_for_another() {
+    // One error before and one after, but the one on line 1 is new
+    let result = lint_fix("const a = 1\nconst b = \n", vec![
+        Replace { name: "test/complete", from: "= \n", to: "= 2\n" },
+        Replace { name: "test/unbalanced", from: "1", to: "(1" },
+    ]);
+    assert_eq!(result.fixed_source, None);
+    assert_eq!(result.fix_error.as_deref(), Some("fixes from test/unbalanced introduce parse errors"));
+}
+
+#[test]
+fn applies_a_fix_that_only_moves_an_existing_parse_error() {
+    let result = lint_fix("const a = 1\nconst b = \n", vec![Replace { name: "test/widen", from: "1", to: "100" }]);
+    assert_eq!(result.fix_error, None);
+    assert_eq!(result.fixed_source.as_deref(), Some("const a = 100\nconst b = \n"));
+}
+
+#[test]
+fn applies_a_fix_that_removes_a_parse_error() {
+    let result = lint_fix("const a = \n", vec![Replace { name: "test/complete", from: "= \n", to: "= 1\n" }]);
+    assert_eq!(result.fix_error, None);
+    assert_eq!(result.fixed_source.as_deref(), Some("const a = 1\n"));
+}
@@ -0,0 +1,141 @@
+use linter_test::formatters::{get_formatter, OutputFormat};
+use linter_test::{DiagnosticWithLocation, Fix, LintResult, Severity, Suggestion};
+use oxc_span::Span;
+use std::path::Path;
+
+// Run with `UPDATE_GOLDEN=1 cargo test --test formatters` to rewrite the fixtures after an intended change.
+fn check_golden(format: OutputFormat, file: &str) {
+    // Keep version bumps from touching the fixtures
+    let actual = get_formatter(format, false).format(&results()).replace(env!("CARGO_PKG_VERSION"), "<version>");
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/formatters").join(file);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    assert_eq!(actual, expected, "output differs from {}", path.display());
+}
+
+fn diagnostic(rule: &str, severity: Severity, message: &str, start: (usize, usize, usize), end: (usize, usize, usize), fix: Option<Fix>) -> DiagnosticWithLocation {
+    DiagnosticWithLocation {
+        rule: rule.to_string(),
+        severity,
+        message: message.to_string(),
+        start_line: start.0,
+        start_column: start.1,
+        end_line: end.0,
+        end_column: end.1,
+        start_column_utf8: start.1,
+        end_column_utf8: end.1,
+        start_offset: start.2,
+        end_offset: end.2,
+        fix_available: fix.is_some(),
+        help: None,
+        fix,
+        suggestions: Vec::new(),
+    }
+}
+
+/// Two files with problems, reported out of position order, and a clean one.
+/// The messages and the path carry characters every format has to escape.
+fn results() -> Vec<LintResult> {
+    let source = "<script setup>\nconsole.log(\"a\")\nconst x = 1\n</script>\n";
+    let mut unused = diagnostic(
+        "no-unused-vars",
+        Severity::Warning,
+        "'x' is defined but never used; <b>\"quoted\"</b> & 'single'",
+        (3, 7, 38),
+        (3, 8, 39),
+        None,
+    );
+    unused.suggestions.push(Suggestion {
+        message: "Remove `x`".to_string(),
+        edits: vec![Fix { span: Span::new(32, 43), replacement: String::new() }],
+    });
+
+    vec![
+        LintResult {
+            path: "src/a,b:c.vue".to_string(),
+            diagnostics: vec![
+                unused,
+                diagnostic(
+                    "no-console",
+                    Severity::Error,
+                    "Unexpected console statement: 100% sure\r\nsecond line",
+                    (2, 1, 15),
+                    (2, 17, 31),
+                    Some(Fix { span: Span::new(15, 31), replacement: "/* console.log */".to_string() }),
+                ),
+            ],
+            fixed_count: 0,
+            source: source.to_string(),
+            fixed_source: None,
+            fix_error: None,
+        },
+        LintResult {
+            path: "src/clean.vue".to_string(),
+            diagnostics: Vec::new(),
+            fixed_count: 0,
+            source: String::new(),
+            fixed_source: None,
+            fix_error: None,
+        },
+        LintResult {
+            path: "src/日本.vue".to_string(),
+            diagnostics: vec![diagnostic("vue/valid-v-for", Severity::Error, "`v-for` directives require a value.", (1, 12, 11), (1, 17, 16), None)],
+            fixed_count: 0,
+            source: "<template><li v-for /></template>\n".to_string(),
+            fixed_source: None,
+            fix_error: None,
+        },
+    ]
+}
+
+#[test]
+fn stylish() {
+    check_golden(OutputFormat::Stylish, "stylish.txt");
+}
+
+#[test]
+fn compact() {
+    check_golden(OutputFormat::Compact, "compact.txt");
+}
+
+#[test]
+fn json() {
+    check_golden(OutputFormat::Json, "json.json");
+}
+
+#[test]
+fn sarif() {
+    check_golden(OutputFormat::Sarif, "sarif.json");
+}
+
+#[test]
+fn junit() {
+    check_golden(OutputFormat::Junit, "junit.xml");
+}
+
+#[test]
+fn checkstyle() {
+    check_golden(OutputFormat::Checkstyle, "checkstyle.xml");
+}
+
+#[test]
+fn github() {
+    check_golden(OutputFormat::Github, "github.txt");
+}
+
+#[test]
+fn gitlab() {
+    check_golden(OutputFormat::Gitlab, "gitlab.json");
+}
+
+#[test]
+fn pretty_reads_top_to_bottom() {
+    let out = get_formatter(OutputFormat::Pretty, false).format(&results());
+    let console = out.find("Unexpected console statement").unwrap();
+    let unused = out.find("'x' is defined but never used").unwrap();
+    assert!(console < unused, "{}", out);
+}
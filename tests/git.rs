@@ -0,0 +1,71 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git").current_dir(repo).args(args).status().unwrap();
+    assert!(status.success(), "git {}", args.join(" "));
+}
+
+/// A repository with non-ASCII file names, configured the way people set up their diffs.
+fn repo() -> PathBuf {
+    let repo = std::env::temp_dir().join(format!("linter-test-git-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&repo);
+    std::fs::create_dir_all(repo.join("comp")).unwrap();
+    git(&repo, &["init", "-q"]);
+    for (key, value) in [
+        ("user.name", "test"),
+        ("user.email", "test@example.com"),
+        ("core.quotePath", "true"),
+        ("diff.noprefix", "true"),
+        ("diff.mnemonicPrefix", "true"),
+    ] {
+        git(&repo, &["config", key, value]);
+    }
+
+    let committed = "<script setup>\nconsole.log(1)\n</script>\n";
+    std::fs::write(repo.join("comp/日本.vue"), committed).unwrap();
+    std::fs::write(repo.join("comp/with space.vue"), committed).unwrap();
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-q", "-m", "init"]);
+
+    let changed = "<script setup>\nconsole.log(1)\nconsole.log(2)\n</script>\n";
+    std::fs::write(repo.join("comp/日本.vue"), changed).unwrap();
+    std::fs::write(repo.join("comp/with space.vue"), changed).unwrap();
+    std::fs::write(repo.join("comp/新規.vue"), committed).unwrap();
+    repo
+}
+
+/// `(file name, line)` of every reported diagnostic.
+fn lint(repo: &Path, args: &[&str]) -> Vec<(String, u64)> {
+    let output = Command::new(env!("CARGO_BIN_EXE_linter-test"))
+        .current_dir(repo)
+        .args(args)
+        .args(["--format", "json", "comp"])
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|e| panic!("{}: {}", e, String::from_utf8_lossy(&output.stderr)));
+
+    let mut found = Vec::new();
+    for result in report["results"].as_array().unwrap() {
+        let name = Path::new(result["path"].as_str().unwrap()).file_name().unwrap().to_string_lossy().into_owned();
+        for d in result["diagnostics"].as_array().unwrap() {
+            found.push((name.clone(), d["range"]["start"]["line"].as_u64().unwrap()));
+        }
+    }
+    found.sort();
+    found
+}
+
+#[test]
+fn changed_lines_in_non_ascii_and_spaced_paths() {
+    let repo = repo();
+    let found = lint(&repo, &["--changed-since", "HEAD", "--diff-lines-only"]);
+    let expected = [("with space.vue", 3), ("新規.vue", 2), ("日本.vue", 3)];
+    assert_eq!(found, expected.map(|(name, line)| (name.to_string(), line)));
+
+    git(&repo, &["add", "comp/日本.vue"]);
+    let found = lint(&repo, &["--staged", "--diff-lines-only"]);
+    assert_eq!(found, vec![("日本.vue".to_string(), 3)]);
+    let _ = std::fs::remove_dir_all(&repo);
+}
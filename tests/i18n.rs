@@ -0,0 +1,89 @@
+use linter_test::rules::i18n::no_missing_keys::NoMissingKeys;
+use linter_test::rules::i18n::no_missing_locale_keys::NoMissingLocaleKeys;
+use linter_test::rules::i18n::unsupported_block::UnsupportedBlock;
+use linter_test::rules::i18n::valid_json::ValidJson;
+use linter_test::{Linter, LinterConfig, Severity};
+use std::path::Path;
+
+fn linter() -> Linter {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(ValidJson));
+    linter.add_rule(Box::new(NoMissingKeys));
+    linter.add_rule(Box::new(NoMissingLocaleKeys));
+    linter.add_rule(Box::new(UnsupportedBlock));
+    linter
+}
+
+/// `(rule, message)` of every diagnostic.
+fn lint(source: &str) -> Vec<(String, String)> {
+    linter().lint_source(Path::new("Test.vue"), source, false).diagnostics.into_iter()
+        .map(|d| (d.rule, d.message))
+        .collect()
+}
+
+#[test]
+fn checks_json_blocks() {
+    let source = r#"<template><p>{{ $t('hello') }} {{ $t('bye') }}</p></template>
+<i18n>{ "en": { "hello": "Hello", "bye": "Bye" }, "ja": { "hello": "こんにちは" } }</i18n>
+"#;
+    let found = lint(source);
+    assert!(found.contains(&("i18n/no-missing-keys".to_string(), "Translation key `bye` is missing in locale `ja`.".to_string())), "{:?}", found);
+    assert!(found.iter().any(|(rule, _)| rule == "i18n/no-missing-locale-keys"), "{:?}", found);
+}
+
+#[test]
+fn warns_about_yaml_blocks() {
+    let source = "<template><p>{{ $t('hello') }}</p></template>\n<i18n lang=\"yaml\">\nen:\n  hello: Hello\n</i18n>\n";
+    let diagnostics = linter().lint_source(Path::new("Test.vue"), source, false).diagnostics;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule, "i18n/unsupported-block");
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(diagnostics[0].message, "<i18n lang=\"yaml\"> blocks aren't supported; only JSON and JSON5 messages are checked.");
+}
+
+#[test]
+fn skipped_blocks_suppress_missing_key_reports() {
+    // `ja.bye` lives in the YAML block, so it isn't really missing
+    let source = r#"<template><p>{{ $t('hello') }} {{ $t('bye') }}</p></template>
+<i18n>{ "en": { "hello": "Hello", "bye": "Bye" }, "ja": { "hello": "こんにちは" } }</i18n>
+<i18n lang="yml" locale="ja">
+bye: さようなら
+</i18n>
+"#;
+    let found = lint(source);
+    assert!(found.iter().all(|(rule, _)| rule == "i18n/unsupported-block"), "{:?}", found);
+    assert_eq!(found.len(), 1, "{:?}", found);
+}
+
+#[test]
+fn bare_t_only_counts_when_it_comes_from_use_i18n() {
+    let messages = r#"<i18n>{ "en": { "hello": "Hello" } }</i18n>"#;
+    let from_use_i18n = format!(
+        "<script setup>\nconst {{ t }} = useI18n()\n</script>\n<template><p>{{{{ t('bye') }}}}</p></template>\n{}\n",
+        messages,
+    );
+    assert_eq!(lint(&from_use_i18n), vec![(
+        "i18n/no-missing-keys".to_string(),
+        "Translation key `bye` is missing in locale `en`.".to_string(),
+    )]);
+
+    let unrelated = format!(
+        "<script setup>\nconst t = (s) => s.trim()\n</script>\n<template><p>{{{{ t('bye') }}}}</p></template>\n{}\n",
+        messages,
+    );
+    assert_eq!(lint(&unrelated), Vec::new());
+}
+
+#[test]
+fn follows_renamed_use_i18n_functions() {
+    let source = r#"<script setup>
+const { t: translate } = useI18n()
+</script>
+<template><p>{{ translate('bye') }}</p></template>
+<i18n>{ "en": { "hello": "Hello" } }</i18n>
+"#;
+    assert_eq!(lint(source), vec![(
+        "i18n/no-missing-keys".to_string(),
+        "Translation key `bye` is missing in locale `en`.".to_string(),
+    )]);
+}
@@ -0,0 +1,52 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// A project with `vendor/` in `.linterignore`.
+fn project(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("linter-test-ignores-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("vendor")).unwrap();
+    std::fs::write(dir.join(".linterignore"), "vendor/\n").unwrap();
+    std::fs::write(dir.join("vendor/Lib.vue"), "<script setup>\nconsole.log(1)\n</script>\n").unwrap();
+    std::fs::write(dir.join("App.vue"), "<script setup>\nconsole.log(1)\n</script>\n").unwrap();
+    dir
+}
+
+fn run(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_linter-test")).current_dir(dir).args(["--format", "json"]).args(args).output().unwrap()
+}
+
+/// The reported file paths.
+fn paths(output: &Output) -> Vec<String> {
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|e| panic!("{}: {}", e, String::from_utf8_lossy(&output.stderr)));
+    let mut paths: Vec<String> = report["results"].as_array().unwrap().iter()
+        .map(|r| r["path"].as_str().unwrap().trim_start_matches("./").to_string())
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn skips_ignored_directories_while_walking() {
+    let dir = project("walk");
+    assert_eq!(paths(&run(&dir, &["."])), vec!["App.vue"]);
+}
+
+#[test]
+fn warns_about_an_explicitly_named_ignored_file() {
+    let dir = project("named");
+    let output = run(&dir, &["vendor/Lib.vue", "App.vue"]);
+    assert_eq!(paths(&output), vec!["App.vue"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("vendor/Lib.vue is ignored by an ignore pattern"), "{}", stderr);
+}
+
+#[test]
+fn no_ignore_lints_everything() {
+    let dir = project("no-ignore");
+    assert_eq!(paths(&run(&dir, &["--no-ignore", "."])), vec!["App.vue", "vendor/Lib.vue"]);
+    let output = run(&dir, &["--no-ignore", "vendor/Lib.vue"]);
+    assert_eq!(paths(&output), vec!["vendor/Lib.vue"]);
+    assert!(output.stderr.is_empty(), "{}", String::from_utf8_lossy(&output.stderr));
+}
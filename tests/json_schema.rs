@@ -0,0 +1,40 @@
+use linter_test::formatters::{get_formatter, OutputFormat};
+use linter_test::rules::no_console::NoConsole;
+use linter_test::rules::vue::no_use_v_if_with_v_for::NoUseVIfWithVFor;
+use linter_test::{Linter, LinterConfig, RuleConfig, Severity};
+use std::path::Path;
+
+const SOURCE: &str = r#"<template>
+  <li v-for="item in items" v-if="item.visible" :key="item.id">{{ item.name }}</li>
+</template>
+
+<script setup>
+console.log(items)
+</script>
+"#;
+
+#[test]
+fn json_report_matches_the_published_schema() {
+    let mut config = LinterConfig::default();
+    config.rules.insert("vue/no-use-v-if-with-v-for".to_string(), RuleConfig::State("warn".to_string()));
+    let mut linter = Linter::new(config);
+    linter.add_rule(Box::new(NoConsole));
+    linter.add_rule(Box::new(NoUseVIfWithVFor));
+
+    let results = vec![
+        linter.lint_source(Path::new("src/List.vue"), SOURCE, false),
+        linter.lint_source(Path::new("src/Clean.vue"), "<template><p /></template>\n", false),
+    ];
+    let diagnostics = &results[0].diagnostics;
+    assert!(diagnostics.iter().any(|d| d.fix.is_some()), "{:?}", diagnostics);
+    assert!(diagnostics.iter().any(|d| !d.suggestions.is_empty()), "{:?}", diagnostics);
+    assert!(diagnostics.iter().any(|d| d.severity == Severity::Warning), "{:?}", diagnostics);
+
+    let report: serde_json::Value = serde_json::from_str(&get_formatter(OutputFormat::Json, false).format(&results)).unwrap();
+    let schema_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("schemas/report.v1.schema.json");
+    let schema: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(schema_path).unwrap()).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+
+    let errors: Vec<String> = validator.iter_errors(&report).map(|e| format!("{} at {}", e, e.instance_path())).collect();
+    assert!(errors.is_empty(), "{}", errors.join("\n"));
+}
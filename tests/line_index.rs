@@ -0,0 +1,87 @@
+use linter_test::line_index::{LineCol, LineIndex};
+
+fn line_col(line: usize, column: usize, column_utf8: usize) -> LineCol {
+    LineCol { line, column, column_utf8 }
+}
+
+#[test]
+fn japanese_in_a_template_maps_to_editor_columns() {
+    let content = "<template>\n<span>{{あ}}</span>\n</template>\n";
+    let index = LineIndex::new(content);
+    let start = content.find("{{").unwrap();
+    let end = content.find("</span>").unwrap();
+
+    assert_eq!(index.line_col(start), line_col(2, 7, 7));
+    assert_eq!(index.line_col(end), line_col(2, 12, 14)); // `あ` is one UTF-16 unit but three bytes
+    assert_eq!(index.offset(2, 7), start);
+    assert_eq!(index.offset(2, 12), end);
+}
+
+#[test]
+fn surrogate_pairs_count_as_two_units() {
+    let content = "a😀b";
+    let index = LineIndex::new(content);
+    assert_eq!(index.line_col(1), line_col(1, 2, 2));
+    assert_eq!(index.line_col(5), line_col(1, 4, 6));
+    assert_eq!(index.offset(1, 4), 5);
+    // Offsets inside the emoji fall back to its start
+    assert_eq!(index.line_col(3), line_col(1, 2, 2));
+    // So do columns between its two units
+    assert_eq!(index.offset(1, 3), 5);
+}
+
+#[test]
+fn crlf_line_endings() {
+    let content = "ab\r\ncd\r\n";
+    let index = LineIndex::new(content);
+    assert_eq!(index.line_col(4), line_col(2, 1, 1));
+    assert_eq!(index.line_col(5), line_col(2, 2, 2));
+    assert_eq!(index.offset(2, 2), 5);
+    // Past the end of a line clamps before the `\r\n`, never between its two bytes
+    assert_eq!(index.offset(1, 10), 2);
+    assert_eq!(index.offset(2, 10), 6);
+}
+
+#[test]
+fn end_of_file() {
+    let index = LineIndex::new("ab\ncd");
+    assert_eq!(index.line_col(5), line_col(2, 3, 3));
+    assert_eq!(index.line_col(100), line_col(2, 3, 3));
+    assert_eq!(index.offset(2, 3), 5);
+    assert_eq!(index.offset(2, 100), 5);
+    assert_eq!(index.offset(3, 1), 5);
+
+    let index = LineIndex::new("ab\n");
+    assert_eq!(index.line_col(3), line_col(2, 1, 1));
+    assert_eq!(index.offset(2, 1), 3);
+
+    let index = LineIndex::new("");
+    assert_eq!(index.line_col(0), line_col(1, 1, 1));
+    assert_eq!(index.offset(1, 1), 0);
+}
+
+#[test]
+fn offset_inverts_line_col() {
+    let content = "const s = 'ニホン😀'\r\n\n  {{ あい }}\tend";
+    let index = LineIndex::new(content);
+    for (offset, _) in content.char_indices() {
+        if content[..offset].ends_with('\r') && content[offset..].starts_with('\n') {
+            continue; // Not a position editors can express
+        }
+        let lc = index.line_col(offset);
+        assert_eq!(index.offset(lc.line, lc.column), offset, "{:?} at {}", lc, offset);
+    }
+}
+
+#[test]
+fn byte_order_mark_is_not_a_column() {
+    let content = "\u{FEFF}const a = 1\nb";
+    let index = LineIndex::new(content);
+    let a = content.find('a').unwrap();
+    assert_eq!(index.line_col(a), line_col(1, 7, 7));
+    assert_eq!(index.line_col(3), line_col(1, 1, 1));
+    assert_eq!(index.line_col(0), line_col(1, 1, 1));
+    assert_eq!(index.line_col(content.len() - 1), line_col(2, 1, 1));
+    assert_eq!(index.offset(1, 7), a);
+    assert_eq!(index.offset(1, 1), 3);
+}
@@ -0,0 +1,174 @@
+use lsp_server::{Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidOpenTextDocument, Exit, Initialized, Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, Initialize, Shutdown};
+use lsp_types::{
+    CodeActionContext, CodeActionOrCommand, CodeActionParams, DidChangeTextDocumentParams,
+    DidOpenTextDocumentParams, InitializeParams, InitializedParams, Position, PublishDiagnosticsParams, Range,
+    TextDocumentContentChangeEvent, TextDocumentIdentifier, TextDocumentItem, Url, VersionedTextDocumentIdentifier,
+};
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+/// Drives the server over stdio the way an editor would, one message at a time.
+struct Client {
+    child: Child,
+    stdin: ChildStdin,
+    messages: Receiver<Message>,
+    next_id: i32,
+}
+
+impl Client {
+    fn start() -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_linter-test"))
+            .arg("lsp")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdin = child.stdin.take().unwrap();
+        let mut stdout = BufReader::new(child.stdout.take().unwrap());
+        let (sender, messages) = mpsc::channel();
+        std::thread::spawn(move || {
+            while let Ok(Some(message)) = Message::read(&mut stdout) {
+                if sender.send(message).is_err() {
+                    break;
+                }
+            }
+        });
+        Client { child, stdin, messages, next_id: 0 }
+    }
+
+    fn send(&mut self, message: Message) {
+        message.write(&mut self.stdin).unwrap();
+        self.stdin.flush().unwrap();
+    }
+
+    fn notify<N: lsp_types::notification::Notification>(&mut self, params: N::Params) {
+        self.send(Message::Notification(Notification::new(N::METHOD.to_string(), params)));
+    }
+
+    /// Sends a request and waits for its response, answering server requests on the way.
+    fn request<R: lsp_types::request::Request>(&mut self, params: R::Params) -> R::Result {
+        self.next_id += 1;
+        let id = RequestId::from(self.next_id);
+        self.send(Message::Request(Request::new(id.clone(), R::METHOD.to_string(), params)));
+        loop {
+            match self.recv() {
+                Message::Response(response) if response.id == id => {
+                    assert!(response.error.is_none(), "{:?}", response.error);
+                    return serde_json::from_value(response.result.unwrap_or_default()).unwrap();
+                }
+                Message::Request(req) => self.send(Message::Response(Response::new_ok(req.id, ()))),
+                _ => {}
+            }
+        }
+    }
+
+    /// The next diagnostics the server publishes for `uri`.
+    fn diagnostics(&mut self, uri: &Url) -> Vec<lsp_types::Diagnostic> {
+        loop {
+            match self.recv() {
+                Message::Notification(not) if not.method == PublishDiagnostics::METHOD => {
+                    let params: PublishDiagnosticsParams = serde_json::from_value(not.params).unwrap();
+                    if params.uri == *uri {
+                        return params.diagnostics;
+                    }
+                }
+                Message::Request(req) => self.send(Message::Response(Response::new_ok(req.id, ()))),
+                _ => {}
+            }
+        }
+    }
+
+    fn recv(&self) -> Message {
+        self.messages.recv_timeout(Duration::from_secs(30)).expect("the server stopped responding")
+    }
+
+    fn open(&mut self, uri: &Url, text: &str) {
+        self.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri.clone(), "vue".to_string(), 1, text.to_string()),
+        });
+    }
+
+    fn change(&mut self, uri: &Url, version: i32, range: Range, text: &str) {
+        self.notify::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier::new(uri.clone(), version),
+            content_changes: vec![TextDocumentContentChangeEvent { range: Some(range), range_length: None, text: text.to_string() }],
+        });
+    }
+}
+
+fn workspace() -> PathBuf {
+    let root = std::env::temp_dir().join(format!("linter-test-lsp-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::create_dir_all(root.join("generated")).unwrap();
+    std::fs::write(root.join(".linterrc.json"), r#"{ "ignorePatterns": ["generated/"] }"#).unwrap();
+    root
+}
+
+fn uri(root: &Path, relative: &str, text: &str) -> Url {
+    let path = root.join(relative);
+    std::fs::write(&path, text).unwrap();
+    Url::from_file_path(path).unwrap()
+}
+
+#[test]
+fn editor_session() {
+    let root = workspace();
+    let source = "<script setup>\nconsole.log(1)\n</script>\n";
+    let app = uri(&root, "src/App.vue", source);
+    let notes = uri(&root, "src/notes.txt", source);
+    let generated = uri(&root, "generated/Api.vue", source);
+
+    let mut client = Client::start();
+    #[allow(deprecated)]
+    let params = InitializeParams { root_uri: Some(Url::from_file_path(&root).unwrap()), ..Default::default() };
+    let init = client.request::<Initialize>(params);
+    assert!(init.capabilities.code_action_provider.is_some());
+    client.notify::<Initialized>(InitializedParams {});
+
+    // didOpen publishes
+    client.open(&app, source);
+    let diagnostics = client.diagnostics(&app);
+    assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+    assert_eq!(diagnostics[0].range, Range::new(Position::new(1, 0), Position::new(1, 14)));
+
+    // Code actions: the fix and the disable comment
+    let actions = client.request::<CodeActionRequest>(CodeActionParams {
+        text_document: TextDocumentIdentifier::new(app.clone()),
+        range: Range::new(Position::new(1, 0), Position::new(1, 0)),
+        context: CodeActionContext { diagnostics: diagnostics.clone(), only: None, trigger_kind: None },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    }).unwrap_or_default();
+    let titles: Vec<String> = actions.iter().map(|a| match a {
+        CodeActionOrCommand::CodeAction(action) => action.title.clone(),
+        CodeActionOrCommand::Command(command) => command.title.clone(),
+    }).collect();
+    assert!(titles.iter().any(|t| t.starts_with("Fix: ")), "{:?}", titles);
+    assert!(titles.contains(&"Disable no-console for this line".to_string()), "{:?}", titles);
+
+    // didChange applies the incremental edit and re-publishes
+    client.change(&app, 2, Range::new(Position::new(1, 0), Position::new(1, 14)), "const a = 1");
+    assert_eq!(client.diagnostics(&app), Vec::new());
+    client.change(&app, 3, Range::new(Position::new(1, 11), Position::new(1, 11)), "; console.warn(a)");
+    assert_eq!(client.diagnostics(&app).len(), 1);
+
+    // Files the CLI would skip get no diagnostics
+    client.open(&notes, source);
+    assert_eq!(client.diagnostics(&notes), Vec::new());
+    client.open(&generated, source);
+    assert_eq!(client.diagnostics(&generated), Vec::new());
+
+    client.request::<Shutdown>(());
+    client.notify::<Exit>(());
+    let status = client.child.wait().unwrap();
+    assert!(status.success(), "{:?}", status);
+    let _ = std::fs::remove_dir_all(&root);
+}
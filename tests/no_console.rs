@@ -0,0 +1,61 @@
+use linter_test::rules::no_console::NoConsole;
+use linter_test::{LintResult, Linter, LinterConfig};
+use std::path::Path;
+
+fn lint_fix(source: &str) -> LintResult {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(NoConsole));
+    linter.lint_source(Path::new("test.js"), source, true)
+}
+
+#[test]
+fn fixes_statement_in_statement_list() {
+    let result = lint_fix("console.log(1)\nfunction f() { console.warn(2) }\n");
+    assert_eq!(result.diagnostics.len(), 2);
+    assert!(result.diagnostics.iter().all(|d| d.fix.is_some()));
+    assert_eq!(result.fixed_source.as_deref(), Some("/* console.log */\nfunction f() { /* console.warn */ }\n"));
+}
+
+#[test]
+fn does_not_fix_braceless_if_body() {
+    let result = lint_fix("if (debug) console.log(1)\nsave()\n");
+    assert_eq!(result.diagnostics.len(), 1);
+    assert!(result.diagnostics[0].fix.is_none());
+    assert_eq!(result.fixed_source, None);
+}
+
+#[test]
+fn does_not_fix_else_body() {
+    let result = lint_fix("if (a) save()\nelse console.log(1)\nnext()\n");
+    assert_eq!(result.diagnostics.len(), 1);
+    assert!(result.diagnostics[0].fix.is_none());
+    assert_eq!(result.fixed_source, None);
+}
+
+#[test]
+fn does_not_fix_arrow_expression_body() {
+    let result = lint_fix("const f = () => console.log(\"x\")\nfunction g() {}\n");
+    assert_eq!(result.diagnostics.len(), 1);
+    assert!(result.diagnostics[0].fix.is_none());
+    assert_eq!(result.fixed_source, None);
+}
+
+#[test]
+fn fixes_inside_arrow_block_body() {
+    let result = lint_fix("const f = () => { console.log(\"x\") }\n");
+    assert_eq!(result.fixed_source.as_deref(), Some("const f = () => { /* console.log */ }\n"));
+}
+
+#[test]
+fn lints_both_scripts_of_an_sfc() {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(NoConsole));
+    let source = "<script>\nconsole.log('plain')\n</script>\n<script setup>\nconsole.log('setup')\n</script>\n";
+    let result = linter.lint_source(Path::new("Component.vue"), source, true);
+    let lines: Vec<usize> = result.diagnostics.iter().map(|d| d.start_line).collect();
+    assert_eq!(lines, [5, 2]);
+    assert_eq!(
+        result.fixed_source.as_deref(),
+        Some("<script>\n/* console.log */\n</script>\n<script setup>\n/* console.log */\n</script>\n"),
+    );
+}
@@ -0,0 +1,62 @@
+use linter_test::rules::vue::no_unused_vars::NoUnusedVars;
+use linter_test::{LintResult, Linter, LinterConfig};
+use std::path::Path;
+
+fn lint(template: &str, fix: bool) -> LintResult {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(NoUnusedVars));
+    let source = format!("<template>\n{}\n</template>\n", template);
+    linter.lint_source(Path::new("Test.vue"), &source, fix)
+}
+
+fn messages(template: &str) -> Vec<String> {
+    lint(template, false).diagnostics.into_iter().map(|d| d.message).collect()
+}
+
+/// The template after `--fix`, or `None` when nothing was fixed.
+fn fixed(template: &str) -> Option<String> {
+    let source = lint(template, true).fixed_source?;
+    Some(source.strip_prefix("<template>\n")?.strip_suffix("\n</template>\n")?.to_string())
+}
+
+#[test]
+fn reports_an_unused_v_for_alias() {
+    assert_eq!(
+        messages("<li v-for=\"(item, index) in items\" :key=\"item.id\">{{ item }}</li>"),
+        vec!["The `v-for` alias 'index' is defined but never used."],
+    );
+}
+
+#[test]
+fn reports_unused_slot_props() {
+    assert_eq!(
+        messages("<MyList v-slot=\"{ foo, bar }\">{{ foo }}</MyList>"),
+        vec!["The slot prop 'bar' is defined but never used."],
+    );
+}
+
+#[test]
+fn ignores_underscored_and_used_aliases() {
+    assert_eq!(messages("<li v-for=\"(_item, index) in items\" :key=\"index\" />"), Vec::<String>::new());
+}
+
+#[test]
+fn removes_an_unused_trailing_alias() {
+    assert_eq!(
+        fixed("<li v-for=\"(item, index) in items\" :key=\"item.id\" />").as_deref(),
+        Some("<li v-for=\"(item) in items\" :key=\"item.id\" />"),
+    );
+    assert_eq!(
+        fixed("<li v-for=\"(v, k, idx) in obj\" :key=\"k\">{{ v }}</li>").as_deref(),
+        Some("<li v-for=\"(v, k) in obj\" :key=\"k\">{{ v }}</li>"),
+    );
+}
+
+#[test]
+fn leaves_an_unused_middle_alias_alone() {
+    let template = "<li v-for=\"(v, k, idx) in obj\" :key=\"idx\">{{ v }}</li>";
+    let result = lint(template, true);
+    assert_eq!(result.diagnostics.len(), 1);
+    assert!(!result.diagnostics[0].fix_available);
+    assert_eq!(result.fixed_source, None);
+}
@@ -0,0 +1,45 @@
+use linter_test::rules::vue::require_v_for_key::RequireVForKey;
+use linter_test::{Linter, LinterConfig};
+use std::path::Path;
+
+/// The 1-based lines of the reported start tags.
+fn lint(template: &str) -> Vec<usize> {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(RequireVForKey));
+    let source = format!("<template>\n{}\n</template>\n", template);
+    linter.lint_source(Path::new("Test.vue"), &source, false).diagnostics.iter().map(|d| d.start_line).collect()
+}
+
+#[test]
+fn reports_unkeyed_elements() {
+    assert_eq!(lint("<li v-for=\"item in items\">{{ item }}</li>"), vec![2]);
+    assert_eq!(lint("<li v-for=\"item in items\" :key=\"item.id\" />\n<li v-for=\"item in items\" v-bind:key=\"item.id\" />"), Vec::<usize>::new());
+}
+
+#[test]
+fn accepts_a_key_on_the_template() {
+    assert_eq!(lint("<template v-for=\"item in items\" :key=\"item.id\">\n  <li>{{ item }}</li>\n</template>"), Vec::<usize>::new());
+}
+
+#[test]
+fn accepts_keyed_children_of_a_template() {
+    let template = "<template v-for=\"item in items\">\n  <dt :key=\"item.id\">{{ item.term }}</dt>\n  <dd :key=\"item.id + '-d'\">{{ item.text }}</dd>\n</template>";
+    assert_eq!(lint(template), Vec::<usize>::new());
+}
+
+#[test]
+fn reports_the_unkeyed_children_of_a_template() {
+    let template = "<template v-for=\"item in items\">\n  <dt :key=\"item.id\">{{ item.term }}</dt>\n  <dd>{{ item.text }}</dd>\n</template>";
+    assert_eq!(lint(template), vec![4]);
+}
+
+#[test]
+fn nested_v_for_is_checked_on_its_own() {
+    let template = "<template v-for=\"group in groups\">\n  <li v-for=\"item in group\" :key=\"item.id\" />\n</template>";
+    assert_eq!(lint(template), Vec::<usize>::new());
+}
+
+#[test]
+fn leaves_a_static_key_to_valid_v_for() {
+    assert_eq!(lint("<li v-for=\"item in items\" key=\"item\" />"), Vec::<usize>::new());
+}
@@ -0,0 +1,65 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn project(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("linter-test-stdin-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn run(dir: &PathBuf, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_linter-test"))
+        .current_dir(dir)
+        .arg("--stdin")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// `(rule, line)` of every diagnostic in a JSON report.
+fn diagnostics(report: &[u8]) -> Vec<(String, u64)> {
+    let report: serde_json::Value = serde_json::from_slice(report).unwrap_or_else(|e| panic!("{}: {}", e, String::from_utf8_lossy(report)));
+    report["results"].as_array().unwrap().iter()
+        .flat_map(|r| r["diagnostics"].as_array().unwrap().clone())
+        .map(|d| (d["rule_id"].as_str().unwrap().to_string(), d["range"]["start"]["line"].as_u64().unwrap()))
+        .collect()
+}
+
+#[test]
+fn fix_writes_the_source_to_stdout_and_the_report_to_stderr() {
+    let dir = project("fix");
+    let output = run(&dir, &["--fix", "--format", "json", "--stdin-filename", "App.vue"], "<script setup>\nconsole.log(1)\n</script>\n");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "<script setup>\n/* console.log */\n</script>\n");
+    assert_eq!(diagnostics(&output.stderr), vec![("no-console".to_string(), 2)]);
+}
+
+#[test]
+fn the_file_name_picks_the_parser() {
+    let dir = project("parser");
+    let source = "const a: number = 1\nconsole.log(a)\n";
+    assert_eq!(diagnostics(&run(&dir, &["--format", "json", "--stdin-filename", "util.ts"], source).stdout), vec![("no-console".to_string(), 2)]);
+    // As plain JavaScript the type annotation doesn't parse, so no rule runs
+    assert_eq!(diagnostics(&run(&dir, &["--format", "json", "--stdin-filename", "util.js"], source).stdout), Vec::new());
+}
+
+#[test]
+fn ignored_file_names_are_echoed_back_unchanged() {
+    let dir = project("ignored");
+    std::fs::write(dir.join(".linterignore"), "vendor/\n").unwrap();
+    let source = "<script setup>\nconsole.log(1)\n</script>\n";
+
+    let output = run(&dir, &["--fix", "--format", "json", "--stdin-filename", "vendor/App.vue"], source);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), source);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("vendor/App.vue is ignored by an ignore pattern"), "{}", stderr);
+
+    let output = run(&dir, &["--no-ignore", "--format", "json", "--stdin-filename", "vendor/App.vue"], source);
+    assert_eq!(diagnostics(&output.stdout), vec![("no-console".to_string(), 2)]);
+}
@@ -0,0 +1,54 @@
+use linter_test::rules::vue::require_v_for_key::RequireVForKey;
+use linter_test::rules::vue::valid_v_for::ValidVFor;
+use linter_test::{Linter, LinterConfig};
+use std::path::Path;
+
+/// `(rule, message)` of every diagnostic, with both `v-for` rules enabled.
+fn lint(template: &str) -> Vec<(String, String)> {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(ValidVFor));
+    linter.add_rule(Box::new(RequireVForKey));
+    let source = format!("<template>\n{}\n</template>\n", template);
+    linter.lint_source(Path::new("Test.vue"), &source, false).diagnostics.into_iter()
+        .map(|d| (d.rule, d.message))
+        .collect()
+}
+
+fn valid_v_for(message: &str) -> Vec<(String, String)> {
+    vec![("vue/valid-v-for".to_string(), message.to_string())]
+}
+
+#[test]
+fn reports_a_malformed_expression() {
+    assert_eq!(lint("<li v-for=\"item in\" :key=\"item\" />"), valid_v_for("Invalid `v-for` expression `item in`; expected `item in list`."));
+}
+
+#[test]
+fn accepts_value_key_and_index_of_an_object() {
+    assert_eq!(lint("<li v-for=\"(v, k, idx) in obj\" :key=\"k\">{{ v }} {{ idx }}</li>"), Vec::new());
+}
+
+#[test]
+fn accepts_a_range() {
+    assert_eq!(lint("<li v-for=\"n in 10\" :key=\"n\">{{ n }}</li>"), Vec::new());
+}
+
+#[test]
+fn reports_the_index_as_key() {
+    assert_eq!(
+        lint("<li v-for=\"(item, index) in items\" :key=\"index\">{{ item }}</li>"),
+        valid_v_for("Using the index `index` as `:key` breaks when the list is reordered; use a unique value from `item`."),
+    );
+    assert_eq!(
+        lint("<li v-for=\"(v, k, idx) in obj\" :key=\"idx\">{{ v }}</li>"),
+        valid_v_for("Using the index `idx` as `:key` breaks when the list is reordered; use a unique value from `v`."),
+    );
+}
+
+#[test]
+fn reports_a_static_key_once() {
+    assert_eq!(
+        lint("<li v-for=\"item in items\" key=\"item\">{{ item }}</li>"),
+        valid_v_for("The static `key` gives every item the same identity; bind a unique value from `item`."),
+    );
+}
@@ -0,0 +1,56 @@
+use linter_test::rules::vue::valid_v_if_chain::ValidVIfChain;
+use linter_test::{LintResult, Linter, LinterConfig};
+use std::path::Path;
+
+fn lint(template: &str, fix: bool) -> LintResult {
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(ValidVIfChain));
+    let source = format!("<template>\n{}\n</template>\n", template);
+    linter.lint_source(Path::new("Test.vue"), &source, fix)
+}
+
+/// `(line, message)` of every diagnostic.
+fn messages(template: &str) -> Vec<(usize, String)> {
+    lint(template, false).diagnostics.into_iter().map(|d| (d.start_line, d.message)).collect()
+}
+
+const MISPLACED_ELSE: &str = "`v-else` must directly follow an element with `v-if` or `v-else-if`.";
+
+#[test]
+fn accepts_a_complete_chain() {
+    assert_eq!(messages("<p v-if=\"a\" />\n<p v-else-if=\"b\" />\n<p v-else />"), Vec::new());
+}
+
+#[test]
+fn reports_v_else_after_v_else() {
+    assert_eq!(messages("<p v-if=\"a\" />\n<p v-else />\n<p v-else />"), vec![(4, MISPLACED_ELSE.to_string())]);
+}
+
+#[test]
+fn reports_v_else_after_a_plain_sibling() {
+    assert_eq!(messages("<p v-if=\"a\" />\n<span />\n<p v-else />"), vec![(4, MISPLACED_ELSE.to_string())]);
+    assert_eq!(messages("<p v-if=\"a\" />\ntext\n<p v-else />"), vec![(4, MISPLACED_ELSE.to_string())]);
+}
+
+#[test]
+fn skips_comments_and_whitespace_between_branches() {
+    assert_eq!(messages("<p v-if=\"a\" />\n\n  <!-- otherwise -->\n  <p v-else />"), Vec::new());
+}
+
+#[test]
+fn removes_the_value_of_v_else() {
+    let template = "<p v-if=\"a\" />\n<p v-else=\"x\" />";
+    assert_eq!(messages(template), vec![(3, "`v-else` doesn't take a value.".to_string())]);
+    assert_eq!(
+        lint(template, true).fixed_source.as_deref(),
+        Some("<template>\n<p v-if=\"a\" />\n<p v-else />\n</template>\n"),
+    );
+}
+
+#[test]
+fn reports_an_element_with_v_if_and_v_else() {
+    assert_eq!(
+        messages("<p v-if=\"a\" v-else />"),
+        vec![(2, "An element can't have more than one of `v-if`, `v-else-if` and `v-else`.".to_string())],
+    );
+}
@@ -0,0 +1,28 @@
+use linter_test::rules::no_console::NoConsole;
+use linter_test::{Linter, LinterConfig};
+use std::fs;
+
+#[cfg(unix)]
+#[test]
+fn fixing_through_a_symlink_keeps_the_link() {
+    let dir = std::env::temp_dir().join(format!("linter-test-symlink-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("real")).unwrap();
+    let target = dir.join("real/app.js");
+    let link = dir.join("app.js");
+    fs::write(&target, "console.log(1)\n").unwrap();
+    std::os::unix::fs::symlink("real/app.js", &link).unwrap();
+
+    let mut linter = Linter::new(LinterConfig::default());
+    linter.add_rule(Box::new(NoConsole));
+    let result = linter.lint_file(&link, true).unwrap();
+    assert_eq!(result.fixed_count, 1);
+
+    assert!(fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+    assert_eq!(fs::read_link(&link).unwrap(), std::path::Path::new("real/app.js"));
+    assert_eq!(fs::read_to_string(&target).unwrap(), "/* console.log */\n");
+    // No temp files left behind next to either path
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+    assert_eq!(fs::read_dir(dir.join("real")).unwrap().count(), 1);
+    let _ = fs::remove_dir_all(&dir);
+}